@@ -0,0 +1,135 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Bounded Values
+//!
+//! Provides a value type whose closed range is validated once at construction.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use super::{
+    error::ArgumentResult,
+    numeric::NumericArgument,
+};
+use std::{
+    fmt,
+    ops::Deref,
+};
+
+/// A value validated to lie within a closed range
+///
+/// A `Bounded<T>` can only be constructed through [`Bounded::try_new`], which
+/// validates the value against its bounds. APIs accepting a `Bounded<T>` therefore
+/// carry the range invariant in their signature and do not need to validate the
+/// value again.
+///
+/// The value is accessible through `Deref`, and the bounds it was validated
+/// against are kept alongside it.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::{ArgumentResult, Bounded};
+///
+/// fn set_volume(volume: Bounded<i32>) {
+///     // No need to validate again, `volume` is known to be in [0, 100]
+///     println!("Volume: {}", *volume);
+/// }
+///
+/// fn handle_request(volume: i32) -> ArgumentResult<()> {
+///     set_volume(Bounded::try_new("volume", volume, 0, 100)?);
+///     Ok(())
+/// }
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounded<T> {
+    value: T,
+    min: T,
+    max: T,
+}
+
+impl<T> Bounded<T>
+where
+    T: NumericArgument + Copy,
+{
+    /// Creates a bounded value, validating that it is within [min, max] range
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name used in error messages
+    /// * `value` - Value to wrap
+    /// * `min` - Minimum value (inclusive)
+    /// * `max` - Maximum value (inclusive)
+    ///
+    /// # Returns
+    ///
+    /// Returns the bounded value if `min <= max` and value is within [min, max]
+    /// range, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::Bounded;
+    ///
+    /// assert_eq!(*Bounded::try_new("percent", 50, 0, 100).unwrap(), 50);
+    /// assert!(Bounded::try_new("percent", 150, 0, 100).is_err());
+    /// assert!(Bounded::try_new("percent", 50, 100, 0).is_err());
+    /// ```
+    pub fn try_new(name: &str, value: T, min: T, max: T) -> ArgumentResult<Self> {
+        let value = value.require_in_closed_range_checked(name, min, max)?;
+        Ok(Bounded { value, min, max })
+    }
+}
+
+impl<T: Copy> Bounded<T> {
+    /// Returns the wrapped value
+    #[inline]
+    pub fn get(&self) -> T {
+        self.value
+    }
+
+    /// Returns the minimum value (inclusive) the value was validated against
+    #[inline]
+    pub fn min(&self) -> T {
+        self.min
+    }
+
+    /// Returns the maximum value (inclusive) the value was validated against
+    #[inline]
+    pub fn max(&self) -> T {
+        self.max
+    }
+
+    /// Consumes the bounded value and returns the wrapped value
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for Bounded<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Bounded<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
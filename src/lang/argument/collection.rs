@@ -18,6 +18,64 @@ use super::error::{
     ArgumentError,
     ArgumentResult,
 };
+use std::{
+    cmp::Ordering,
+    fmt::{
+        self,
+        Display,
+    },
+};
+
+/// Order required by `CollectionArgument::require_ordered`
+///
+/// The strict variants reject equal adjacent elements, while the non-strict
+/// variants accept them.
+///
+/// # Author
+///
+/// Haixing Hu
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    /// Each element is less than or equal to the next one
+    Ascending,
+    /// Each element is strictly less than the next one
+    AscendingStrict,
+    /// Each element is greater than or equal to the next one
+    Descending,
+    /// Each element is strictly greater than the next one
+    DescendingStrict,
+}
+
+impl SortOrder {
+    /// Returns whether the ordering of two adjacent elements satisfies this order
+    ///
+    /// Incomparable elements (`None`, e.g. NaN) never satisfy any order.
+    fn accepts(self, ordering: Option<Ordering>) -> bool {
+        match self {
+            SortOrder::Ascending => {
+                matches!(ordering, Some(Ordering::Less | Ordering::Equal))
+            }
+            SortOrder::AscendingStrict => ordering == Some(Ordering::Less),
+            SortOrder::Descending => {
+                matches!(ordering, Some(Ordering::Greater | Ordering::Equal))
+            }
+            SortOrder::DescendingStrict => ordering == Some(Ordering::Greater),
+        }
+    }
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            SortOrder::Ascending => "ascending",
+            SortOrder::AscendingStrict => "strictly ascending",
+            SortOrder::Descending => "descending",
+            SortOrder::DescendingStrict => "strictly descending",
+        };
+        f.write_str(text)
+    }
+}
 
 /// # Collection Argument Validation Trait
 ///
@@ -70,6 +128,9 @@ use super::error::{
 /// Haixing Hu
 ///
 pub trait CollectionArgument {
+    /// The type of the elements in the collection
+    type Item;
+
     /// Validate that the collection is not empty
     ///
     /// # Parameters
@@ -182,163 +243,970 @@ pub trait CollectionArgument {
         min_length: usize,
         max_length: usize,
     ) -> ArgumentResult<&Self>;
-}
-
-impl<T> CollectionArgument for [T] {
-    fn require_non_empty(&self, name: &str) -> ArgumentResult<&Self> {
-        if self.is_empty() {
-            return Err(ArgumentError::new(format!(
-                "Collection '{}' cannot be empty",
-                name
-            )));
-        }
-        Ok(self)
-    }
-
-    fn require_length_be(&self, name: &str, length: usize) -> ArgumentResult<&Self> {
-        let actual_length = self.len();
-        if actual_length != length {
-            return Err(ArgumentError::new(format!(
-                "Collection '{}' length must be {} but was {}",
-                name, length, actual_length
-            )));
-        }
-        Ok(self)
-    }
-
-    fn require_length_at_least(&self, name: &str, min_length: usize) -> ArgumentResult<&Self> {
-        let actual_length = self.len();
-        if actual_length < min_length {
-            return Err(ArgumentError::new(format!(
-                "Collection '{}' length must be at least {} but was {}",
-                name, min_length, actual_length
-            )));
-        }
-        Ok(self)
-    }
 
-    fn require_length_at_most(&self, name: &str, max_length: usize) -> ArgumentResult<&Self> {
-        let actual_length = self.len();
-        if actual_length > max_length {
-            return Err(ArgumentError::new(format!(
-                "Collection '{}' length must be at most {} but was {}",
-                name, max_length, actual_length
-            )));
-        }
-        Ok(self)
-    }
+    /// Validate that the collection is not empty and return its length
+    ///
+    /// This is equivalent to `require_non_empty` followed by `len()`, and saves
+    /// the extra call in hot paths where the length is needed right after the
+    /// validation.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(length)` if the collection is not empty, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::CollectionArgument;
+    ///
+    /// let items = vec![1, 2, 3];
+    /// assert_eq!(items.non_empty_len("items").unwrap(), 3);
+    ///
+    /// let empty: Vec<i32> = vec![];
+    /// assert!(empty.non_empty_len("items").is_err());
+    /// ```
+    fn non_empty_len(&self, name: &str) -> ArgumentResult<usize>;
 
-    fn require_length_in_range(
+    /// Validate that the collection length is within the specified range and return it
+    ///
+    /// This is equivalent to `require_length_in_range` followed by `len()`, which
+    /// is convenient when the length drives a subsequent allocation.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `min_length` - Minimum length (inclusive)
+    /// * `max_length` - Maximum length (inclusive)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(length)` if the length is within range, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::CollectionArgument;
+    ///
+    /// let items = vec![1, 2, 3];
+    /// assert_eq!(items.length_in_range("items", 1, 5).unwrap(), 3);
+    /// assert!(items.length_in_range("items", 4, 5).is_err());
+    /// ```
+    fn length_in_range(
         &self,
         name: &str,
         min_length: usize,
         max_length: usize,
-    ) -> ArgumentResult<&Self> {
-        let actual_length = self.len();
-        if actual_length < min_length || actual_length > max_length {
-            return Err(ArgumentError::new(format!(
-                "Collection '{}' length must be in range [{}, {}] but was {}",
-                name, min_length, max_length, actual_length
-            )));
-        }
-        Ok(self)
-    }
-}
-
-impl<T> CollectionArgument for Vec<T> {
-    fn require_non_empty(&self, name: &str) -> ArgumentResult<&Self> {
-        if self.is_empty() {
-            return Err(ArgumentError::new(format!(
-                "Collection '{}' cannot be empty",
-                name
-            )));
-        }
-        Ok(self)
-    }
+    ) -> ArgumentResult<usize>;
 
-    fn require_length_be(&self, name: &str, length: usize) -> ArgumentResult<&Self> {
-        let actual_length = self.len();
-        if actual_length != length {
-            return Err(ArgumentError::new(format!(
-                "Collection '{}' length must be {} but was {}",
-                name, length, actual_length
-            )));
-        }
-        Ok(self)
-    }
+    /// Validate that the collection length fits in a `u16`
+    ///
+    /// Useful before storing the length in a narrower count field, e.g. during
+    /// serialization, where an `as` cast would silently truncate it.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the length is at most `u16::MAX`, otherwise returns an
+    /// error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::CollectionArgument;
+    ///
+    /// assert!(vec![0u8; 100].require_length_fits_in_u16("items").is_ok());
+    /// assert!(vec![0u8; 70_000].require_length_fits_in_u16("items").is_err());
+    /// ```
+    fn require_length_fits_in_u16(&self, name: &str) -> ArgumentResult<&Self>;
 
-    fn require_length_at_least(&self, name: &str, min_length: usize) -> ArgumentResult<&Self> {
-        let actual_length = self.len();
-        if actual_length < min_length {
-            return Err(ArgumentError::new(format!(
-                "Collection '{}' length must be at least {} but was {}",
-                name, min_length, actual_length
-            )));
-        }
-        Ok(self)
-    }
+    /// Validate that the collection length fits in a `u32`
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the length is at most `u32::MAX`, otherwise returns an
+    /// error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::CollectionArgument;
+    ///
+    /// assert!(vec![0u8; 100].require_length_fits_in_u32("items").is_ok());
+    /// ```
+    fn require_length_fits_in_u32(&self, name: &str) -> ArgumentResult<&Self>;
 
-    fn require_length_at_most(&self, name: &str, max_length: usize) -> ArgumentResult<&Self> {
-        let actual_length = self.len();
-        if actual_length > max_length {
-            return Err(ArgumentError::new(format!(
-                "Collection '{}' length must be at most {} but was {}",
-                name, max_length, actual_length
-            )));
-        }
-        Ok(self)
-    }
+    /// Find the indices of all elements that do not satisfy the predicate
+    ///
+    /// This is a diagnostic helper that, unlike the `require_*` methods, does not
+    /// stop at the first failing element.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - Validation condition applied to each element
+    ///
+    /// # Returns
+    ///
+    /// Returns the indices of the elements for which `predicate` returns `false`,
+    /// in ascending order
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::CollectionArgument;
+    ///
+    /// let values = vec![1, -2, 3, -4];
+    /// assert_eq!(values.find_invalid(|&v| v > 0), vec![1, 3]);
+    /// ```
+    fn find_invalid<F>(&self, predicate: F) -> Vec<usize>
+    where
+        F: FnMut(&Self::Item) -> bool;
 
-    fn require_length_in_range(
+    /// Validate that all elements satisfy the predicate, reporting every failure
+    ///
+    /// The error message lists the indices of all elements that do not satisfy the
+    /// predicate, which helps when debugging large batches.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `predicate` - Validation condition applied to each element
+    /// * `error_msg` - Error message describing the condition
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if all elements satisfy the predicate, otherwise returns an error
+    /// containing the indices of all failing elements
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::CollectionArgument;
+    ///
+    /// let values = vec![1, -2, 3, -4];
+    /// let err = values
+    ///     .require_all_collecting("values", |&v| v > 0, "must be positive")
+    ///     .unwrap_err();
+    /// assert_eq!(
+    ///     err.message(),
+    ///     "Collection 'values': elements at indices [1, 3] must be positive"
+    /// );
+    /// ```
+    fn require_all_collecting<F>(
         &self,
         name: &str,
-        min_length: usize,
-        max_length: usize,
-    ) -> ArgumentResult<&Self> {
-        let actual_length = self.len();
-        if actual_length < min_length || actual_length > max_length {
-            return Err(ArgumentError::new(format!(
-                "Collection '{}' length must be in range [{}, {}] but was {}",
-                name, min_length, max_length, actual_length
-            )));
-        }
-        Ok(self)
-    }
-}
+        predicate: F,
+        error_msg: &str,
+    ) -> ArgumentResult<&Self>
+    where
+        F: FnMut(&Self::Item) -> bool;
 
-/// Validate that all elements in the collection are non-null
-///
-/// Checks a collection of Option types to ensure all elements are Some.
-///
-/// # Parameters
-///
-/// * `name` - Parameter name
-/// * `collection` - Collection to validate
-///
-/// # Returns
-///
-/// Returns `Ok(())` if all elements are non-null, otherwise returns an error containing the index of the first null element
-///
-/// # Examples
-///
-/// ```rust,ignore
-/// use prism3_core::lang::argument::require_element_non_null;
-///
-/// let items = vec![Some(1), Some(2), Some(3)];
-/// assert!(require_element_non_null("items", &items).is_ok());
-///
-/// let items_with_none = vec![Some(1), None, Some(3)];
-/// assert!(require_element_non_null("items", &items_with_none).is_err());
-/// ```
-///
-/// # Author
-///
-/// Haixing Hu
-///
-pub fn require_element_non_null<T>(name: &str, collection: &[Option<T>]) -> ArgumentResult<()> {
-    for (index, item) in collection.iter().enumerate() {
-        if item.is_none() {
-            return Err(ArgumentError::new(format!(
+    /// Validate that the collection is not empty and split off its first element
+    ///
+    /// Convenient for algorithms that process a required head element followed by
+    /// an optional tail.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok((first, rest))` if the collection is not empty, otherwise returns
+    /// an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::CollectionArgument;
+    ///
+    /// let values = [1, 2, 3];
+    /// assert_eq!(values.first_and_rest("values").unwrap(), (&1, &[2, 3][..]));
+    ///
+    /// let empty: [i32; 0] = [];
+    /// assert!(empty.first_and_rest("values").is_err());
+    /// ```
+    fn first_and_rest(&self, name: &str) -> ArgumentResult<(&Self::Item, &[Self::Item])>;
+
+    /// Validate that no two consecutive elements are equal
+    ///
+    /// Useful for sequence policies such as "no two identical characters in a row"
+    /// when applied to a collection of characters.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if no two adjacent elements are equal, otherwise returns an
+    /// error reporting the indices of the first repeated pair
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::CollectionArgument;
+    ///
+    /// let chars: Vec<char> = "abab".chars().collect();
+    /// assert!(chars.require_no_adjacent_repeats("password").is_ok());
+    ///
+    /// let chars: Vec<char> = "aabb".chars().collect();
+    /// assert!(chars.require_no_adjacent_repeats("password").is_err());
+    /// ```
+    fn require_no_adjacent_repeats(&self, name: &str) -> ArgumentResult<&Self>
+    where
+        Self::Item: PartialEq;
+
+    /// Validate that the collection length is a multiple of a factor
+    ///
+    /// Useful when processing data in fixed-size chunks, e.g. RGBA pixels stored
+    /// in a flat buffer.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `factor` - Chunk size that the length must be a multiple of
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the length is a multiple of `factor`, otherwise returns
+    /// an error. A zero `factor` is always rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::CollectionArgument;
+    ///
+    /// assert!(vec![0u8; 12].require_length_multiple_of("pixels", 4).is_ok());
+    /// assert!(vec![0u8; 10].require_length_multiple_of("pixels", 4).is_err());
+    /// ```
+    fn require_length_multiple_of(&self, name: &str, factor: usize) -> ArgumentResult<&Self>;
+
+    /// Validate that the collection is sorted in the given order
+    ///
+    /// Elements that cannot be compared with each other (e.g. NaN) are treated as
+    /// out of order.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `order` - Required order of the elements
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if every pair of adjacent elements satisfies `order`,
+    /// otherwise returns an error reporting the index of the first element that
+    /// breaks the order
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::{CollectionArgument, SortOrder};
+    ///
+    /// assert!([1, 2, 2, 3].require_ordered("values", SortOrder::Ascending).is_ok());
+    /// assert!([1, 2, 2, 3].require_ordered("values", SortOrder::AscendingStrict).is_err());
+    /// assert!([3, 2, 1].require_ordered("values", SortOrder::DescendingStrict).is_ok());
+    /// ```
+    fn require_ordered(&self, name: &str, order: SortOrder) -> ArgumentResult<&Self>
+    where
+        Self::Item: PartialOrd;
+
+    /// Validate that the collection contains the given elements as a subsequence
+    ///
+    /// The elements of `needle` must appear in the collection in the same order,
+    /// but not necessarily contiguously. Useful e.g. to check that the required steps
+    /// of a workflow appear in order.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `needle` - Elements that must appear in order
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if `needle` is a subsequence of the collection, otherwise
+    /// returns an error reporting the first element of `needle` that could not be
+    /// matched
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::CollectionArgument;
+    ///
+    /// let steps = ["checkout", "build", "test", "deploy"];
+    /// assert!(steps.require_subsequence("steps", &["build", "deploy"]).is_ok());
+    /// assert!(steps.require_subsequence("steps", &["deploy", "build"]).is_err());
+    /// ```
+    fn require_subsequence(&self, name: &str, needle: &[Self::Item]) -> ArgumentResult<&Self>
+    where
+        Self::Item: PartialEq + Display;
+
+    /// Validate that every element is within closed interval
+    ///
+    /// Elements that cannot be compared with the bounds (e.g. NaN) are treated as
+    /// out of range.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `min` - Minimum element value (inclusive)
+    /// * `max` - Maximum element value (inclusive)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if every element is within [min, max] range, otherwise
+    /// returns an error reporting the index and value of the first element out of
+    /// range
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::CollectionArgument;
+    ///
+    /// assert!(vec![1u8, 2, 3].require_all_in_range("data", 0, 10).is_ok());
+    /// assert!(vec![1u8, 20, 3].require_all_in_range("data", 0, 10).is_err());
+    /// ```
+    fn require_all_in_range(
+        &self,
+        name: &str,
+        min: Self::Item,
+        max: Self::Item,
+    ) -> ArgumentResult<&Self>
+    where
+        Self::Item: PartialOrd + Display + Copy;
+
+    /// Validate that the number of elements matching a predicate is within closed interval
+    ///
+    /// Useful for quorum-style rules such as "at least 2 of these must be enabled".
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `predicate` - Function returning `true` for matching elements
+    /// * `min` - Minimum number of matching elements (inclusive)
+    /// * `max` - Maximum number of matching elements (inclusive)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the number of matching elements is within [min, max]
+    /// range, otherwise returns an error reporting the number of matching elements
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::CollectionArgument;
+    ///
+    /// let flags = [true, false, true];
+    /// assert!(flags.require_count_matching("flags", |f| *f, 2, 5).is_ok());
+    /// assert!(flags.require_count_matching("flags", |f| !*f, 2, 5).is_err());
+    /// ```
+    fn require_count_matching<F>(
+        &self,
+        name: &str,
+        predicate: F,
+        min: usize,
+        max: usize,
+    ) -> ArgumentResult<&Self>
+    where
+        F: FnMut(&Self::Item) -> bool;
+
+    /// Validate that no two elements are equal according to a custom equality
+    ///
+    /// Useful when the element type does not implement `Eq`, e.g. to reject
+    /// floating-point values that are approximately equal. Every pair of elements
+    /// is compared, so the check takes O(n²) time.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `eq` - Function returning `true` if two elements are considered equal
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if no two elements are considered equal, otherwise returns
+    /// an error reporting the indices of the first such pair
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::CollectionArgument;
+    ///
+    /// let approx_eq = |a: &f64, b: &f64| (a - b).abs() < 1e-9;
+    /// assert!([0.1, 0.2, 0.3].require_distinct_by("values", approx_eq).is_ok());
+    /// assert!([0.3, 0.1 + 0.2].require_distinct_by("values", approx_eq).is_err());
+    /// ```
+    fn require_distinct_by<F>(&self, name: &str, eq: F) -> ArgumentResult<&Self>
+    where
+        F: Fn(&Self::Item, &Self::Item) -> bool;
+}
+
+impl<T> CollectionArgument for [T] {
+    type Item = T;
+
+    fn require_non_empty(&self, name: &str) -> ArgumentResult<&Self> {
+        if self.is_empty() {
+            return Err(ArgumentError::new(format!(
+                "Collection '{}' cannot be empty",
+                name
+            )));
+        }
+        Ok(self)
+    }
+
+    fn require_length_be(&self, name: &str, length: usize) -> ArgumentResult<&Self> {
+        let actual_length = self.len();
+        if actual_length != length {
+            return Err(ArgumentError::new(format!(
+                "Collection '{}' length must be {} but was {}",
+                name, length, actual_length
+            )));
+        }
+        Ok(self)
+    }
+
+    fn require_length_at_least(&self, name: &str, min_length: usize) -> ArgumentResult<&Self> {
+        let actual_length = self.len();
+        if actual_length < min_length {
+            return Err(ArgumentError::new(format!(
+                "Collection '{}' length must be at least {} but was {}",
+                name, min_length, actual_length
+            )));
+        }
+        Ok(self)
+    }
+
+    fn require_length_at_most(&self, name: &str, max_length: usize) -> ArgumentResult<&Self> {
+        let actual_length = self.len();
+        if actual_length > max_length {
+            return Err(ArgumentError::new(format!(
+                "Collection '{}' length must be at most {} but was {}",
+                name, max_length, actual_length
+            )));
+        }
+        Ok(self)
+    }
+
+    fn require_length_in_range(
+        &self,
+        name: &str,
+        min_length: usize,
+        max_length: usize,
+    ) -> ArgumentResult<&Self> {
+        let actual_length = self.len();
+        if actual_length < min_length || actual_length > max_length {
+            return Err(ArgumentError::new(format!(
+                "Collection '{}' length must be in range [{}, {}] but was {}",
+                name, min_length, max_length, actual_length
+            )));
+        }
+        Ok(self)
+    }
+
+    fn non_empty_len(&self, name: &str) -> ArgumentResult<usize> {
+        self.require_non_empty(name)?;
+        Ok(self.len())
+    }
+
+    fn length_in_range(
+        &self,
+        name: &str,
+        min_length: usize,
+        max_length: usize,
+    ) -> ArgumentResult<usize> {
+        self.require_length_in_range(name, min_length, max_length)?;
+        Ok(self.len())
+    }
+
+    fn require_length_fits_in_u16(&self, name: &str) -> ArgumentResult<&Self> {
+        require_length_fits(name, self.len(), u16::MAX.into(), "u16")?;
+        Ok(self)
+    }
+
+    fn require_length_fits_in_u32(&self, name: &str) -> ArgumentResult<&Self> {
+        require_length_fits(name, self.len(), u32::MAX.into(), "u32")?;
+        Ok(self)
+    }
+
+    fn find_invalid<F>(&self, mut predicate: F) -> Vec<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .enumerate()
+            .filter(|(_, item)| !predicate(item))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    fn require_all_collecting<F>(
+        &self,
+        name: &str,
+        predicate: F,
+        error_msg: &str,
+    ) -> ArgumentResult<&Self>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let invalid = self.find_invalid(predicate);
+        if !invalid.is_empty() {
+            return Err(ArgumentError::new(format!(
+                "Collection '{}': elements at indices {:?} {}",
+                name, invalid, error_msg
+            )));
+        }
+        Ok(self)
+    }
+
+    fn first_and_rest(&self, name: &str) -> ArgumentResult<(&T, &[T])> {
+        self.require_non_empty(name)?;
+        // The collection was just validated to be non-empty
+        Ok(self.split_first().expect("collection is non-empty"))
+    }
+
+    fn require_no_adjacent_repeats(&self, name: &str) -> ArgumentResult<&Self>
+    where
+        T: PartialEq,
+    {
+        if let Some(index) = self.windows(2).position(|pair| pair[0] == pair[1]) {
+            return Err(ArgumentError::new(format!(
+                "Collection '{}' has equal adjacent elements at indices {} and {}",
+                name,
+                index,
+                index + 1
+            )));
+        }
+        Ok(self)
+    }
+
+    fn require_length_multiple_of(&self, name: &str, factor: usize) -> ArgumentResult<&Self> {
+        if factor == 0 {
+            return Err(ArgumentError::new(format!(
+                "Collection '{}' length factor must be positive",
+                name
+            )));
+        }
+        if self.len() % factor != 0 {
+            return Err(ArgumentError::new(format!(
+                "Collection '{}' length ({}) must be a multiple of {}",
+                name,
+                self.len(),
+                factor
+            )));
+        }
+        Ok(self)
+    }
+
+    fn require_ordered(&self, name: &str, order: SortOrder) -> ArgumentResult<&Self>
+    where
+        T: PartialOrd,
+    {
+        if let Some(index) = self
+            .windows(2)
+            .position(|pair| !order.accepts(pair[0].partial_cmp(&pair[1])))
+        {
+            return Err(ArgumentError::new(format!(
+                "Collection '{}' must be in {} order but element at index {} breaks it",
+                name,
+                order,
+                index + 1
+            )));
+        }
+        Ok(self)
+    }
+
+    fn require_subsequence(&self, name: &str, needle: &[T]) -> ArgumentResult<&Self>
+    where
+        T: PartialEq + Display,
+    {
+        let mut remaining = self.iter();
+        for (index, expected) in needle.iter().enumerate() {
+            if !remaining.any(|item| item == expected) {
+                return Err(ArgumentError::new(format!(
+                    "Collection '{}' must contain the required elements in order but required element {} ('{}') is missing or out of order",
+                    name, index, expected
+                )));
+            }
+        }
+        Ok(self)
+    }
+
+    fn require_all_in_range(&self, name: &str, min: T, max: T) -> ArgumentResult<&Self>
+    where
+        T: PartialOrd + Display + Copy,
+    {
+        let in_range = |item: &T| min <= *item && *item <= max;
+        if let Some((index, item)) = self.iter().enumerate().find(|(_, item)| !in_range(item)) {
+            return Err(ArgumentError::new(format!(
+                "Collection '{}': element at index {} must be in range [{}, {}] but was: {}",
+                name, index, min, max, item
+            )));
+        }
+        Ok(self)
+    }
+
+    fn require_count_matching<F>(
+        &self,
+        name: &str,
+        mut predicate: F,
+        min: usize,
+        max: usize,
+    ) -> ArgumentResult<&Self>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let count = self.iter().filter(|item| predicate(item)).count();
+        if count < min || count > max {
+            return Err(ArgumentError::new(format!(
+                "Collection '{}': expected between {} and {} matching elements but found {}",
+                name, min, max, count
+            )));
+        }
+        Ok(self)
+    }
+
+    fn require_distinct_by<F>(&self, name: &str, eq: F) -> ArgumentResult<&Self>
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        for (i, first) in self.iter().enumerate() {
+            for (j, second) in self.iter().enumerate().skip(i + 1) {
+                if eq(first, second) {
+                    return Err(ArgumentError::new(format!(
+                        "Collection '{}' must contain distinct elements but elements at indices {} and {} are equal",
+                        name, i, j
+                    )));
+                }
+            }
+        }
+        Ok(self)
+    }
+}
+
+impl<T> CollectionArgument for Vec<T> {
+    type Item = T;
+
+    fn require_non_empty(&self, name: &str) -> ArgumentResult<&Self> {
+        if self.is_empty() {
+            return Err(ArgumentError::new(format!(
+                "Collection '{}' cannot be empty",
+                name
+            )));
+        }
+        Ok(self)
+    }
+
+    fn require_length_be(&self, name: &str, length: usize) -> ArgumentResult<&Self> {
+        let actual_length = self.len();
+        if actual_length != length {
+            return Err(ArgumentError::new(format!(
+                "Collection '{}' length must be {} but was {}",
+                name, length, actual_length
+            )));
+        }
+        Ok(self)
+    }
+
+    fn require_length_at_least(&self, name: &str, min_length: usize) -> ArgumentResult<&Self> {
+        let actual_length = self.len();
+        if actual_length < min_length {
+            return Err(ArgumentError::new(format!(
+                "Collection '{}' length must be at least {} but was {}",
+                name, min_length, actual_length
+            )));
+        }
+        Ok(self)
+    }
+
+    fn require_length_at_most(&self, name: &str, max_length: usize) -> ArgumentResult<&Self> {
+        let actual_length = self.len();
+        if actual_length > max_length {
+            return Err(ArgumentError::new(format!(
+                "Collection '{}' length must be at most {} but was {}",
+                name, max_length, actual_length
+            )));
+        }
+        Ok(self)
+    }
+
+    fn require_length_in_range(
+        &self,
+        name: &str,
+        min_length: usize,
+        max_length: usize,
+    ) -> ArgumentResult<&Self> {
+        let actual_length = self.len();
+        if actual_length < min_length || actual_length > max_length {
+            return Err(ArgumentError::new(format!(
+                "Collection '{}' length must be in range [{}, {}] but was {}",
+                name, min_length, max_length, actual_length
+            )));
+        }
+        Ok(self)
+    }
+
+    fn non_empty_len(&self, name: &str) -> ArgumentResult<usize> {
+        self.as_slice().non_empty_len(name)
+    }
+
+    fn length_in_range(
+        &self,
+        name: &str,
+        min_length: usize,
+        max_length: usize,
+    ) -> ArgumentResult<usize> {
+        self.as_slice()
+            .length_in_range(name, min_length, max_length)
+    }
+
+    fn require_length_fits_in_u16(&self, name: &str) -> ArgumentResult<&Self> {
+        self.as_slice().require_length_fits_in_u16(name)?;
+        Ok(self)
+    }
+
+    fn require_length_fits_in_u32(&self, name: &str) -> ArgumentResult<&Self> {
+        self.as_slice().require_length_fits_in_u32(name)?;
+        Ok(self)
+    }
+
+    fn find_invalid<F>(&self, predicate: F) -> Vec<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.as_slice().find_invalid(predicate)
+    }
+
+    fn require_all_collecting<F>(
+        &self,
+        name: &str,
+        predicate: F,
+        error_msg: &str,
+    ) -> ArgumentResult<&Self>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.as_slice()
+            .require_all_collecting(name, predicate, error_msg)?;
+        Ok(self)
+    }
+
+    fn first_and_rest(&self, name: &str) -> ArgumentResult<(&T, &[T])> {
+        self.as_slice().first_and_rest(name)
+    }
+
+    fn require_no_adjacent_repeats(&self, name: &str) -> ArgumentResult<&Self>
+    where
+        T: PartialEq,
+    {
+        self.as_slice().require_no_adjacent_repeats(name)?;
+        Ok(self)
+    }
+
+    fn require_length_multiple_of(&self, name: &str, factor: usize) -> ArgumentResult<&Self> {
+        self.as_slice().require_length_multiple_of(name, factor)?;
+        Ok(self)
+    }
+
+    fn require_ordered(&self, name: &str, order: SortOrder) -> ArgumentResult<&Self>
+    where
+        T: PartialOrd,
+    {
+        self.as_slice().require_ordered(name, order)?;
+        Ok(self)
+    }
+
+    fn require_subsequence(&self, name: &str, needle: &[T]) -> ArgumentResult<&Self>
+    where
+        T: PartialEq + Display,
+    {
+        self.as_slice().require_subsequence(name, needle)?;
+        Ok(self)
+    }
+
+    fn require_all_in_range(&self, name: &str, min: T, max: T) -> ArgumentResult<&Self>
+    where
+        T: PartialOrd + Display + Copy,
+    {
+        self.as_slice().require_all_in_range(name, min, max)?;
+        Ok(self)
+    }
+
+    fn require_count_matching<F>(
+        &self,
+        name: &str,
+        predicate: F,
+        min: usize,
+        max: usize,
+    ) -> ArgumentResult<&Self>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.as_slice()
+            .require_count_matching(name, predicate, min, max)?;
+        Ok(self)
+    }
+
+    fn require_distinct_by<F>(&self, name: &str, eq: F) -> ArgumentResult<&Self>
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        self.as_slice().require_distinct_by(name, eq)?;
+        Ok(self)
+    }
+}
+
+/// Check that a collection length does not exceed the maximum of an integer type
+fn require_length_fits(name: &str, length: usize, max: u64, type_name: &str) -> ArgumentResult<()> {
+    // `usize` is at most 64 bits wide on all supported platforms
+    if length as u64 > max {
+        return Err(ArgumentError::new(format!(
+            "Collection '{}' length ({}) exceeds the maximum of {} for {}",
+            name, length, max, type_name
+        )));
+    }
+    Ok(())
+}
+
+/// `Vec` argument validation trait
+///
+/// Provides validation methods that only make sense for `Vec`s, such as checks on
+/// the allocated capacity. Useful in memory-sensitive code to catch accidental huge
+/// pre-allocations.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::{VecArgument, ArgumentResult};
+///
+/// fn store(buffer: Vec<u8>) -> ArgumentResult<()> {
+///     buffer.require_capacity_ratio_at_most("buffer", 2)?;
+///     Ok(())
+/// }
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+pub trait VecArgument {
+    /// Validate that the capacity does not exceed a maximum
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `max_cap` - Maximum capacity (inclusive)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the capacity is at most `max_cap`, otherwise returns an
+    /// error reporting the capacity and the length
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::VecArgument;
+    ///
+    /// let v: Vec<u8> = Vec::with_capacity(16);
+    /// assert!(v.require_capacity_at_most("v", 16).is_ok());
+    /// assert!(v.require_capacity_at_most("v", 8).is_err());
+    /// ```
+    fn require_capacity_at_most(&self, name: &str, max_cap: usize) -> ArgumentResult<&Self>;
+
+    /// Validate that the capacity does not exceed a multiple of the length
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `ratio` - Maximum ratio of capacity to length (inclusive)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if `capacity <= len * ratio`, otherwise returns an error
+    /// reporting the capacity and the length. An empty `Vec` therefore only passes if
+    /// it has not allocated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::VecArgument;
+    ///
+    /// let mut v: Vec<u8> = Vec::with_capacity(100);
+    /// v.push(1);
+    /// assert!(v.require_capacity_ratio_at_most("v", 2).is_err());
+    /// ```
+    fn require_capacity_ratio_at_most(&self, name: &str, ratio: usize) -> ArgumentResult<&Self>;
+}
+
+impl<T> VecArgument for Vec<T> {
+    fn require_capacity_at_most(&self, name: &str, max_cap: usize) -> ArgumentResult<&Self> {
+        if self.capacity() > max_cap {
+            return Err(ArgumentError::new(format!(
+                "Collection '{}' capacity must be at most {} but was {} (length {})",
+                name,
+                max_cap,
+                self.capacity(),
+                self.len()
+            )));
+        }
+        Ok(self)
+    }
+
+    fn require_capacity_ratio_at_most(&self, name: &str, ratio: usize) -> ArgumentResult<&Self> {
+        if self.capacity() > self.len().saturating_mul(ratio) {
+            return Err(ArgumentError::new(format!(
+                "Collection '{}' capacity must be at most {} times its length but was {} (length {})",
+                name,
+                ratio,
+                self.capacity(),
+                self.len()
+            )));
+        }
+        Ok(self)
+    }
+}
+
+/// Validate that all elements in the collection are non-null
+///
+/// Checks a collection of Option types to ensure all elements are Some.
+///
+/// # Parameters
+///
+/// * `name` - Parameter name
+/// * `collection` - Collection to validate
+///
+/// # Returns
+///
+/// Returns `Ok(())` if all elements are non-null, otherwise returns an error containing the index of the first null element
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::require_element_non_null;
+///
+/// let items = vec![Some(1), Some(2), Some(3)];
+/// assert!(require_element_non_null("items", &items).is_ok());
+///
+/// let items_with_none = vec![Some(1), None, Some(3)];
+/// assert!(require_element_non_null("items", &items_with_none).is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+pub fn require_element_non_null<T>(name: &str, collection: &[Option<T>]) -> ArgumentResult<()> {
+    for (index, item) in collection.iter().enumerate() {
+        if item.is_none() {
+            return Err(ArgumentError::new(format!(
                 "Collection '{}': element at index {} cannot be null",
                 name, index
             )));
@@ -346,3 +1214,285 @@ pub fn require_element_non_null<T>(name: &str, collection: &[Option<T>]) -> Argu
     }
     Ok(())
 }
+
+/// Validate that all elements in the collection are non-null and borrow their values
+///
+/// Unlike unwrapping the options by value, this leaves the collection owned by the
+/// caller and returns references to the inner values.
+///
+/// # Parameters
+///
+/// * `name` - Parameter name
+/// * `collection` - Collection to validate
+///
+/// # Returns
+///
+/// Returns references to the inner values, in order, if all elements are non-null,
+/// otherwise returns an error containing the index of the first null element
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::require_all_some;
+///
+/// let items = vec![Some(1), Some(2), Some(3)];
+/// assert_eq!(require_all_some("items", &items).unwrap(), vec![&1, &2, &3]);
+///
+/// let items_with_none = vec![Some(1), None, Some(3)];
+/// assert!(require_all_some("items", &items_with_none).is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+pub fn require_all_some<'a, T>(
+    name: &str,
+    collection: &'a [Option<T>],
+) -> ArgumentResult<Vec<&'a T>> {
+    collection
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            item.as_ref().ok_or_else(|| {
+                ArgumentError::new(format!(
+                    "Collection '{}': element at index {} cannot be null",
+                    name, index
+                ))
+            })
+        })
+        .collect()
+}
+
+/// Validate that a vector is not empty, taking and returning ownership
+///
+/// Unlike `CollectionArgument::require_non_empty`, which returns a reference, this
+/// consumes the vector and hands it back on success, so a freshly constructed
+/// vector can be validated inline without an extra binding.
+///
+/// # Parameters
+///
+/// * `name` - Parameter name
+/// * `collection` - Vector to validate
+///
+/// # Returns
+///
+/// Returns `Ok(collection)` if the vector is not empty, otherwise returns an error
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::require_non_empty_owned;
+///
+/// let ids = require_non_empty_owned("ids", vec![1, 2, 3])?;
+/// assert_eq!(ids, vec![1, 2, 3]);
+/// assert!(require_non_empty_owned("ids", Vec::<i32>::new()).is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+pub fn require_non_empty_owned<T>(name: &str, collection: Vec<T>) -> ArgumentResult<Vec<T>> {
+    collection.require_non_empty(name)?;
+    Ok(collection)
+}
+
+/// Validate that the length of a vector is within range, taking and returning ownership
+///
+/// This is the owning counterpart of `CollectionArgument::require_length_in_range`.
+///
+/// # Parameters
+///
+/// * `name` - Parameter name
+/// * `collection` - Vector to validate
+/// * `min_length` - Minimum length (inclusive)
+/// * `max_length` - Maximum length (inclusive)
+///
+/// # Returns
+///
+/// Returns `Ok(collection)` if the length is within range, otherwise returns an
+/// error
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::require_length_in_range_owned;
+///
+/// let tags = require_length_in_range_owned("tags", vec!["a", "b"], 1, 5)?;
+/// assert_eq!(tags.len(), 2);
+/// assert!(require_length_in_range_owned("tags", vec!["a"], 2, 5).is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+pub fn require_length_in_range_owned<T>(
+    name: &str,
+    collection: Vec<T>,
+    min_length: usize,
+    max_length: usize,
+) -> ArgumentResult<Vec<T>> {
+    collection.require_length_in_range(name, min_length, max_length)?;
+    Ok(collection)
+}
+
+/// Validate that two collections have the same length
+///
+/// Useful for parallel collections that must align element by element, e.g. a list
+/// of keys and a list of values that will be zipped together.
+///
+/// # Parameters
+///
+/// * `name1` - First collection name
+/// * `a` - First collection
+/// * `name2` - Second collection name
+/// * `b` - Second collection
+///
+/// # Returns
+///
+/// Returns `Ok(length)` with the common length if both collections have the same
+/// length, otherwise returns an error
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::require_same_length;
+///
+/// let keys = vec!["a", "b", "c"];
+/// let values = vec![1, 2, 3];
+/// assert_eq!(require_same_length("keys", &keys, "values", &values).unwrap(), 3);
+///
+/// let short = vec![1, 2];
+/// assert!(require_same_length("keys", &keys, "values", &short).is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+pub fn require_same_length<A, B>(
+    name1: &str,
+    a: &[A],
+    name2: &str,
+    b: &[B],
+) -> ArgumentResult<usize> {
+    if a.len() != b.len() {
+        return Err(ArgumentError::new(format!(
+            "Collections '{}' ({}) and '{}' ({}) must have the same length",
+            name1,
+            a.len(),
+            name2,
+            b.len()
+        )));
+    }
+    Ok(a.len())
+}
+
+/// Validate that the lengths of two collections satisfy a relation
+///
+/// Generalizes `require_same_length` to arbitrary relations between the lengths,
+/// e.g. that the length of a flattened row buffer is a multiple of the number of
+/// header columns.
+///
+/// # Parameters
+///
+/// * `name_a` - First collection name
+/// * `a` - First collection
+/// * `name_b` - Second collection name
+/// * `b` - Second collection
+/// * `rel` - Relation called with the lengths of `a` and `b`
+/// * `desc` - Description of the relation used in the error message
+///
+/// # Returns
+///
+/// Returns `Ok(())` if `rel(a.len(), b.len())` holds, otherwise returns an error
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::require_length_relation;
+///
+/// let headers = vec!["id", "name"];
+/// let cells = vec!["1", "Alice", "2", "Bob"];
+/// assert!(require_length_relation(
+///     "headers",
+///     &headers,
+///     "cells",
+///     &cells,
+///     |h, c| h > 0 && c % h == 0,
+///     "length of 'cells' must be a multiple of length of 'headers'",
+/// )
+/// .is_ok());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+pub fn require_length_relation<A, B, F>(
+    name_a: &str,
+    a: &[A],
+    name_b: &str,
+    b: &[B],
+    rel: F,
+    desc: &str,
+) -> ArgumentResult<()>
+where
+    F: Fn(usize, usize) -> bool,
+{
+    if !rel(a.len(), b.len()) {
+        return Err(ArgumentError::new(format!(
+            "Collections '{}' ({}) and '{}' ({}) violate the length relation: {}",
+            name_a,
+            a.len(),
+            name_b,
+            b.len(),
+            desc
+        )));
+    }
+    Ok(())
+}
+
+/// Validate that a value is an element of a collection
+///
+/// Useful when the collection of allowed values is the primary subject, e.g. when
+/// checking that a selected key exists in a known set.
+///
+/// # Parameters
+///
+/// * `name` - Parameter name of the value
+/// * `value` - Value to look up
+/// * `collection` - Allowed values
+///
+/// # Returns
+///
+/// Returns `Ok(())` if the collection contains the value, otherwise returns an error
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::require_member;
+///
+/// let roles = ["admin", "user"];
+/// assert!(require_member("role", &"admin", &roles).is_ok());
+/// assert!(require_member("role", &"guest", &roles).is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+pub fn require_member<T>(name: &str, value: &T, collection: &[T]) -> ArgumentResult<()>
+where
+    T: PartialEq + Display,
+{
+    if !collection.contains(value) {
+        return Err(ArgumentError::new(format!(
+            "Value '{}' ({}) is not among the allowed values",
+            name, value
+        )));
+    }
+    Ok(())
+}
@@ -124,6 +124,87 @@ pub fn check_argument_fmt(condition: bool, message: String) -> ArgumentResult<()
     Ok(())
 }
 
+/// Check if an argument condition is true and return the validated value
+///
+/// Unlike `check_argument_with_message`, which returns `()`, this function threads
+/// the value through on success so it can be used in fluent validation chains.
+///
+/// # Parameters
+///
+/// * `value` - The value being validated
+/// * `condition` - The condition to check
+/// * `message` - Error message when condition is not satisfied
+///
+/// # Returns
+///
+/// Returns `Ok(value)` if the condition is true, otherwise returns an error with custom message
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::check_that;
+///
+/// let x = 42;
+/// let x = check_that(x, x > 0, "x must be positive")?;
+/// assert_eq!(x, 42);
+///
+/// assert!(check_that(-1, false, "x must be positive").is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+pub fn check_that<T>(value: T, condition: bool, message: &str) -> ArgumentResult<T> {
+    if !condition {
+        return Err(ArgumentError::new(message));
+    }
+    Ok(value)
+}
+
+/// Check if a value satisfies a predicate, building the error lazily on failure
+///
+/// Unlike `check_that`, the condition is evaluated on the value and the error is
+/// only constructed when the check fails, so expensive error messages cost
+/// nothing on the success path.
+///
+/// # Parameters
+///
+/// * `value` - The value being validated
+/// * `predicate` - Function returning `true` if the value is valid
+/// * `make_err` - Function building the error from the invalid value
+///
+/// # Returns
+///
+/// Returns `Ok(value)` if the predicate is satisfied, otherwise returns the error
+/// built by `make_err`
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::{require, ArgumentError};
+///
+/// let port = require(8080, |p| *p >= 1024, |p| {
+///     ArgumentError::new(format!("port {} is reserved", p))
+/// })?;
+/// assert_eq!(port, 8080);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+pub fn require<T, P, F>(value: T, predicate: P, make_err: F) -> ArgumentResult<T>
+where
+    P: FnOnce(&T) -> bool,
+    F: FnOnce(&T) -> ArgumentError,
+{
+    if !predicate(&value) {
+        return Err(make_err(&value));
+    }
+    Ok(value)
+}
+
 /// Check if a state condition is true
 ///
 /// Used to validate the state of an object or system. Similar to `check_argument` but semantically for state checking.
@@ -272,6 +353,41 @@ pub fn check_element_index(index: usize, size: usize) -> ArgumentResult<usize> {
     Ok(index)
 }
 
+/// Check if an index is within the slice and return the element at that index
+///
+/// Combines `check_element_index` with the indexing operation, so that the
+/// validated index and the accessed index can never diverge.
+///
+/// # Parameters
+///
+/// * `slice` - The slice to access
+/// * `index` - The index to check
+/// * `name` - Collection name
+///
+/// # Returns
+///
+/// Returns a reference to the element if the index is valid, otherwise returns an error
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::get_checked;
+///
+/// assert_eq!(get_checked(&[10, 20, 30], 1, "values").unwrap(), &20);
+/// assert!(get_checked(&[10, 20, 30], 3, "values").is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+pub fn get_checked<'a, T>(slice: &'a [T], index: usize, name: &str) -> ArgumentResult<&'a T> {
+    check_element_index(index, slice.len()).map_err(|error| {
+        ArgumentError::new(format!("Collection '{}': {}", name, error.message()))
+    })?;
+    Ok(&slice[index])
+}
+
 /// Check if a position index is within valid range
 ///
 /// Position index can equal size (for insertion operations).
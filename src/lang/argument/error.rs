@@ -14,7 +14,10 @@
 //!
 //! Haixing Hu
 
-use std::fmt;
+use std::{
+    collections::HashMap,
+    fmt,
+};
 
 /// Argument validation error
 ///
@@ -23,6 +26,7 @@ use std::fmt;
 /// # Features
 ///
 /// - Contains detailed error messages
+/// - Records the name of the offending parameter when known
 /// - Implements standard error traits
 /// - Supports conversion from strings
 ///
@@ -35,13 +39,19 @@ use std::fmt;
 /// assert_eq!(error.message(), "Invalid argument");
 /// ```
 ///
+/// # Equality
+///
+/// Two errors are equal when their messages are equal. The recorded parameter
+/// name is metadata and does not take part in the comparison.
+///
 /// # Author
 ///
 /// Haixing Hu
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct ArgumentError {
     message: String,
+    parameter: Option<String>,
 }
 
 impl ArgumentError {
@@ -61,7 +71,87 @@ impl ArgumentError {
     pub fn new(message: impl Into<String>) -> Self {
         Self {
             message: message.into(),
+            parameter: None,
+        }
+    }
+
+    /// Create a new argument error about a named parameter
+    ///
+    /// Produces the message `Parameter '{name}' {detail}`, the standard format used
+    /// by all parameter validators. Custom validators should use this constructor to
+    /// match the message style of the built-in ones.
+    ///
+    /// The parameter name is also recorded in the error and can be retrieved with
+    /// [`ArgumentError::parameter`].
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `detail` - Description of the violation, following the parameter name
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::ArgumentError;
+    ///
+    /// let error = ArgumentError::for_param("port", "must be a privileged port");
+    /// assert_eq!(error.message(), "Parameter 'port' must be a privileged port");
+    /// assert_eq!(error.parameter(), Some("port"));
+    /// ```
+    pub fn for_param(name: &str, detail: &str) -> Self {
+        Self {
+            message: param_message(name, detail),
+            parameter: Some(name.to_string()),
+        }
+    }
+
+    /// Create a new argument error from a complete message and an optional parameter name
+    ///
+    /// Unlike [`ArgumentError::for_param`], the message is used as is, which allows
+    /// rebuilding an error from the parts of another one.
+    ///
+    /// # Parameters
+    ///
+    /// * `message` - Error message
+    /// * `parameter` - Name of the parameter the error is about, if known
+    #[cfg(feature = "typed-error")]
+    pub(crate) fn with_parameter(message: impl Into<String>, parameter: Option<String>) -> Self {
+        Self {
+            message: message.into(),
+            parameter,
+        }
+    }
+
+    /// Create a new argument error, rejecting a blank message
+    ///
+    /// An empty error message produces a confusing empty `Display` output, so this
+    /// constructor guards against accidentally empty error text.
+    ///
+    /// # Parameters
+    ///
+    /// * `message` - Error message
+    ///
+    /// # Returns
+    ///
+    /// Returns the new error if the message is not empty or whitespace-only,
+    /// otherwise returns an error describing the invalid message
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::ArgumentError;
+    ///
+    /// assert!(ArgumentError::new_checked("Value cannot be negative").is_ok());
+    /// assert!(ArgumentError::new_checked("").is_err());
+    /// ```
+    pub fn new_checked(message: impl Into<String>) -> ArgumentResult<Self> {
+        let message = message.into();
+        if message.trim().is_empty() {
+            return Err(Self::new(
+                "Argument error message cannot be empty or contain only whitespace characters",
+            ));
         }
+        Ok(Self::new(message))
     }
 
     /// Get the error message
@@ -72,8 +162,61 @@ impl ArgumentError {
     pub fn message(&self) -> &str {
         &self.message
     }
+
+    /// Get the name of the parameter the error is about
+    ///
+    /// The parameter name is best-effort: it is recorded by the built-in parameter
+    /// validators and by [`ArgumentError::for_param`], but not by errors built from a
+    /// plain message, such as collection-level errors or errors created with
+    /// [`ArgumentError::new`].
+    ///
+    /// # Returns
+    ///
+    /// Returns the parameter name if the error was created with
+    /// [`ArgumentError::for_param`], otherwise returns `None`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::ArgumentError;
+    ///
+    /// assert_eq!(ArgumentError::for_param("port", "is invalid").parameter(), Some("port"));
+    /// assert_eq!(ArgumentError::new("Invalid argument").parameter(), None);
+    /// ```
+    pub fn parameter(&self) -> Option<&str> {
+        self.parameter.as_deref()
+    }
+}
+
+/// Format the standard message of an error about a named parameter
+///
+/// # Parameters
+///
+/// * `name` - Parameter name
+/// * `detail` - Description of the violation, following the parameter name
+///
+/// # Returns
+///
+/// Returns the message `Parameter '{name}' {detail}`
+pub(crate) fn param_message(name: &str, detail: &str) -> String {
+    format!("Parameter '{}' {}", name, detail)
+}
+
+impl Default for ArgumentError {
+    /// Create an argument error with the generic message `"invalid argument"`
+    fn default() -> Self {
+        Self::new("invalid argument")
+    }
+}
+
+impl PartialEq for ArgumentError {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message
+    }
 }
 
+impl Eq for ArgumentError {}
+
 impl fmt::Display for ArgumentError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.message)
@@ -117,3 +260,244 @@ impl From<&str> for ArgumentError {
 /// Haixing Hu
 ///
 pub type ArgumentResult<T> = Result<T, ArgumentError>;
+
+/// Extension methods for `ArgumentResult`
+///
+/// Provides combinators that are useful in long validation chains.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::{ArgumentResultExt, NumericArgument};
+///
+/// let port = 8080
+///     .require_in_closed_range("port", 1, 65535)
+///     .tap_ok(|port| tracing::debug!("validated port {}", port))
+///     .tap_err(|error| tracing::warn!("{}", error));
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+pub trait ArgumentResultExt<T> {
+    /// Call a function with the value if the result is `Ok`
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - Function observing the value
+    ///
+    /// # Returns
+    ///
+    /// Returns the result unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::{ArgumentResult, ArgumentResultExt};
+    ///
+    /// let result: ArgumentResult<i32> = Ok(42);
+    /// let result = result.tap_ok(|value| println!("value: {}", value));
+    /// assert_eq!(result.unwrap(), 42);
+    /// ```
+    fn tap_ok<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&T);
+
+    /// Call a function with the error if the result is `Err`
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - Function observing the error
+    ///
+    /// # Returns
+    ///
+    /// Returns the result unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::{ArgumentError, ArgumentResult, ArgumentResultExt};
+    ///
+    /// let result: ArgumentResult<i32> = Err(ArgumentError::new("invalid"));
+    /// let result = result.tap_err(|error| eprintln!("error: {}", error));
+    /// assert!(result.is_err());
+    /// ```
+    fn tap_err<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&ArgumentError);
+
+    /// Return the value, or fall back to a default after reporting the error
+    ///
+    /// Useful for non-fatal configuration validation, where an invalid value is
+    /// replaced with a safe default while the problem is logged.
+    ///
+    /// # Parameters
+    ///
+    /// * `default` - Value to return if the result is `Err`
+    /// * `warn` - Function called with the error before falling back to `default`
+    ///
+    /// # Returns
+    ///
+    /// Returns the value if the result is `Ok`, otherwise returns `default`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::{ArgumentResultExt, NumericArgument};
+    ///
+    /// let workers = 0
+    ///     .require_positive("workers")
+    ///     .unwrap_or_default_with_warn(4, |error| tracing::warn!("{}", error));
+    /// assert_eq!(workers, 4);
+    /// ```
+    fn unwrap_or_default_with_warn<F>(self, default: T, warn: F) -> T
+    where
+        F: FnOnce(&ArgumentError);
+
+    /// Split the result into an optional value and an optional error
+    ///
+    /// Useful for APIs that expose an `Option` but validate internally, so that
+    /// the error can be stored or logged separately from the value.
+    ///
+    /// # Returns
+    ///
+    /// Returns `(Some(value), None)` if the result is `Ok`, otherwise returns
+    /// `(None, Some(error))`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::{ArgumentResultExt, NumericArgument};
+    ///
+    /// let (value, error) = 0.require_positive("workers").into_option_logging();
+    /// assert!(value.is_none());
+    /// assert!(error.is_some());
+    /// ```
+    fn into_option_logging(self) -> (Option<T>, Option<ArgumentError>);
+}
+
+impl<T> ArgumentResultExt<T> for ArgumentResult<T> {
+    fn tap_ok<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&T),
+    {
+        if let Ok(value) = &self {
+            f(value);
+        }
+        self
+    }
+
+    fn tap_err<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&ArgumentError),
+    {
+        if let Err(error) = &self {
+            f(error);
+        }
+        self
+    }
+
+    fn unwrap_or_default_with_warn<F>(self, default: T, warn: F) -> T
+    where
+        F: FnOnce(&ArgumentError),
+    {
+        match self {
+            Ok(value) => value,
+            Err(error) => {
+                warn(&error);
+                default
+            }
+        }
+    }
+
+    fn into_option_logging(self) -> (Option<T>, Option<ArgumentError>) {
+        match self {
+            Ok(value) => (Some(value), None),
+            Err(error) => (None, Some(error)),
+        }
+    }
+}
+
+/// Combine three argument validation results into a tuple
+///
+/// Useful when constructing a validated value from several independently
+/// validated arguments, avoiding a chain of nested `?` expressions.
+///
+/// # Parameters
+///
+/// * `a` - First validation result
+/// * `b` - Second validation result
+/// * `c` - Third validation result
+///
+/// # Returns
+///
+/// Returns `Ok((a, b, c))` if all results are `Ok`, otherwise returns the first
+/// error in argument order
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::{zip_results3, NumericArgument, StringArgument};
+///
+/// let (port, host, retries) = zip_results3(
+///     8080.require_in_closed_range("port", 1, 65535),
+///     "localhost".require_non_blank("host"),
+///     3.require_non_negative("retries"),
+/// )?;
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub fn zip_results3<A, B, C>(
+    a: ArgumentResult<A>,
+    b: ArgumentResult<B>,
+    c: ArgumentResult<C>,
+) -> ArgumentResult<(A, B, C)> {
+    Ok((a?, b?, c?))
+}
+
+/// Group argument errors by the parameter they are about
+///
+/// Useful for rendering validation errors next to the corresponding form fields.
+/// Errors that are not about a named parameter are grouped under the `None` key.
+///
+/// # Parameters
+///
+/// * `errors` - Errors to group
+///
+/// # Returns
+///
+/// Returns a map from parameter name to the errors about that parameter, in their
+/// original order
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::{group_by_parameter, ArgumentError};
+///
+/// let groups = group_by_parameter(vec![
+///     ArgumentError::for_param("port", "must be positive"),
+///     ArgumentError::for_param("port", "must be at most 65535"),
+///     ArgumentError::new("Invalid configuration"),
+/// ]);
+/// assert_eq!(groups[&Some("port".to_string())].len(), 2);
+/// assert_eq!(groups[&None].len(), 1);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub fn group_by_parameter(
+    errors: Vec<ArgumentError>,
+) -> HashMap<Option<String>, Vec<ArgumentError>> {
+    let mut groups: HashMap<Option<String>, Vec<ArgumentError>> = HashMap::new();
+    for error in errors {
+        groups
+            .entry(error.parameter.clone())
+            .or_default()
+            .push(error);
+    }
+    groups
+}
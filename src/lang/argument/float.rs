@@ -0,0 +1,342 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Float Argument Validation
+//!
+//! Provides validation functionality specific to floating-point type arguments.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use super::{
+    collection::{
+        CollectionArgument,
+        SortOrder,
+    },
+    error::{
+        ArgumentError,
+        ArgumentResult,
+    },
+    numeric::NumericArgument,
+};
+use std::cmp::Ordering;
+
+/// Float argument validation trait
+///
+/// Provides validation methods that only make sense for floating-point types.
+///
+/// The range checks of `NumericArgument` are based on `PartialOrd`, so a `NaN`
+/// value passes checks such as `require_in_closed_range` because every comparison
+/// with `NaN` is `false`. The methods of this trait reject `NaN` and infinite
+/// values explicitly.
+///
+/// # Features
+///
+/// - Rejects `NaN` and infinite values with a dedicated error message
+/// - Implemented for `f32` and `f64`
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::{FloatArgument, ArgumentResult};
+///
+/// fn set_opacity(opacity: f64) -> ArgumentResult<f64> {
+///     opacity.require_finite_in_range("opacity", 0.0, 1.0)
+/// }
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+pub trait FloatArgument: NumericArgument {
+    /// Validate that value is finite, i.e. neither `NaN` nor infinite
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if value is finite, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::FloatArgument;
+    ///
+    /// assert!(1.5f64.require_finite("value").is_ok());
+    /// assert!(f64::NAN.require_finite("value").is_err());
+    /// ```
+    fn require_finite(self, name: &str) -> ArgumentResult<Self>;
+
+    /// Validate that value is finite and within closed interval
+    ///
+    /// Non-finite values are reported as such before the range is checked.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `min` - Minimum value (inclusive)
+    /// * `max` - Maximum value (inclusive)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if value is finite and within [min, max] range, otherwise
+    /// returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::FloatArgument;
+    ///
+    /// assert!(0.5f64.require_finite_in_range("value", 0.0, 1.0).is_ok());
+    /// assert!(f64::INFINITY.require_finite_in_range("value", 0.0, 1.0).is_err());
+    /// assert!(f64::NAN.require_finite_in_range("value", 0.0, 1.0).is_err());
+    /// ```
+    fn require_finite_in_range(self, name: &str, min: Self, max: Self) -> ArgumentResult<Self>;
+
+    /// Validate that value is finite and return its sign
+    ///
+    /// Unlike `signum()`, which returns `1.0` for `+0.0` and `NaN` for `NaN`, both
+    /// zeros yield `0` and non-finite values are rejected.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(-1)`, `Ok(0)` or `Ok(1)` if value is finite and negative, zero
+    /// or positive respectively, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::FloatArgument;
+    ///
+    /// assert_eq!((-3.0f64).require_finite_signum("x").unwrap(), -1);
+    /// assert_eq!(0.0f64.require_finite_signum("x").unwrap(), 0);
+    /// assert!(f64::NAN.require_finite_signum("x").is_err());
+    /// ```
+    fn require_finite_signum(self, name: &str) -> ArgumentResult<i8>;
+
+    /// Validate that value is within a tolerance of the nearest integer
+    ///
+    /// Useful for counts or indices that are stored as floating-point values.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `epsilon` - Maximum allowed distance from the nearest integer
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if `|value - round(value)| <= epsilon`, otherwise returns
+    /// an error. `NaN` and infinite values are always rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::FloatArgument;
+    ///
+    /// assert!(2.9999999f64.require_near_integer("count", 1e-6).is_ok());
+    /// assert!(3.4f64.require_near_integer("count", 1e-6).is_err());
+    /// ```
+    fn require_near_integer(self, name: &str, epsilon: Self) -> ArgumentResult<Self>;
+
+    /// Validate that value is within a tolerance of an integer and return that integer
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `epsilon` - Maximum allowed distance from the nearest integer
+    ///
+    /// # Returns
+    ///
+    /// Returns the rounded value as `i64` if value passes `require_near_integer`
+    /// and the rounded value fits in `i64`, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::FloatArgument;
+    ///
+    /// assert_eq!(2.9999999f64.as_integer_checked("count", 1e-6).unwrap(), 3);
+    /// assert!(1e20f64.as_integer_checked("count", 1e-6).is_err());
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn as_integer_checked(self, name: &str, epsilon: Self) -> ArgumentResult<i64>;
+}
+
+macro_rules! impl_float_argument {
+    ($($float:ty),* $(,)?) => {
+        $(
+            impl FloatArgument for $float {
+                fn require_finite(self, name: &str) -> ArgumentResult<Self> {
+                    if !self.is_finite() {
+                        return Err(ArgumentError::for_param(name, &format!("must be finite but was: {}", self)));
+                    }
+                    Ok(self)
+                }
+
+                fn require_finite_in_range(
+                    self,
+                    name: &str,
+                    min: Self,
+                    max: Self,
+                ) -> ArgumentResult<Self> {
+                    self.require_finite(name)?
+                        .require_in_closed_range(name, min, max)
+                }
+
+                fn require_near_integer(self, name: &str, epsilon: Self) -> ArgumentResult<Self> {
+                    // NaN and infinite values yield a NaN distance, which is incomparable
+                    let distance = (self - self.round()).abs();
+                    if !matches!(
+                        distance.partial_cmp(&epsilon),
+                        Some(Ordering::Less | Ordering::Equal)
+                    ) {
+                        return Err(ArgumentError::for_param(
+                            name,
+                            &format!("({}) must be approximately integral", self),
+                        ));
+                    }
+                    Ok(self)
+                }
+
+                fn as_integer_checked(self, name: &str, epsilon: Self) -> ArgumentResult<i64> {
+                    let rounded = self.require_near_integer(name, epsilon)?.round() as f64;
+                    // `i64::MAX as f64` rounds up to 2^63, which is itself out of range
+                    if rounded < i64::MIN as f64 || rounded >= i64::MAX as f64 {
+                        return Err(ArgumentError::for_param(
+                            name,
+                            &format!("({}) is out of the range of i64", self),
+                        ));
+                    }
+                    Ok(rounded as i64)
+                }
+
+                fn require_finite_signum(self, name: &str) -> ArgumentResult<i8> {
+                    let value = self.require_finite(name)?;
+                    Ok(if value > 0.0 {
+                        1
+                    } else if value < 0.0 {
+                        -1
+                    } else {
+                        0
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_float_argument!(f32, f64);
+
+/// Float slice argument validation trait
+///
+/// Provides validation methods for slices of floating-point values, e.g. to guard
+/// numerical routines against `NaN` and infinite input.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::{FloatSliceArgument, ArgumentResult};
+///
+/// fn mean(samples: &[f64]) -> ArgumentResult<f64> {
+///     let samples = samples.require_all_finite("samples")?;
+///     Ok(samples.iter().sum::<f64>() / samples.len() as f64)
+/// }
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+pub trait FloatSliceArgument {
+    /// Validate that all elements are finite, i.e. neither `NaN` nor infinite
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Collection name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if all elements are finite, otherwise returns an error
+    /// reporting the index of the first non-finite element
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::FloatSliceArgument;
+    ///
+    /// assert!([1.0, 2.5].require_all_finite("samples").is_ok());
+    /// assert!([1.0, f64::NAN].require_all_finite("samples").is_err());
+    /// ```
+    fn require_all_finite(&self, name: &str) -> ArgumentResult<&Self>;
+
+    /// Validate that the elements are sorted in ascending order and none is NaN
+    ///
+    /// Since every comparison with `NaN` is `false`, an order check based on
+    /// `PartialOrd` alone may let `NaN` slip through. This method rejects any `NaN`
+    /// element before checking that the elements are in ascending (non-decreasing)
+    /// order. Infinite elements are allowed.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Collection name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if no element is `NaN` and the elements are in ascending
+    /// order, otherwise returns an error reporting the index of the first `NaN` or
+    /// of the first element that breaks the order
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::FloatSliceArgument;
+    ///
+    /// assert!([1.0, 2.0, 2.0, 3.5].require_sorted_floats("thresholds").is_ok());
+    /// assert!([1.0, f64::NAN, 3.5].require_sorted_floats("thresholds").is_err());
+    /// assert!([2.0, 1.0].require_sorted_floats("thresholds").is_err());
+    /// ```
+    fn require_sorted_floats(&self, name: &str) -> ArgumentResult<&Self>;
+}
+
+macro_rules! impl_float_slice_argument {
+    ($($float:ty),* $(,)?) => {
+        $(
+            impl FloatSliceArgument for [$float] {
+                fn require_all_finite(&self, name: &str) -> ArgumentResult<&Self> {
+                    if let Some(index) = self.iter().position(|value| !value.is_finite()) {
+                        return Err(ArgumentError::new(format!(
+                            "Collection '{}': element at index {} is not finite ({})",
+                            name, index, self[index]
+                        )));
+                    }
+                    Ok(self)
+                }
+
+                fn require_sorted_floats(&self, name: &str) -> ArgumentResult<&Self> {
+                    if let Some(index) = self.iter().position(|value| value.is_nan()) {
+                        return Err(ArgumentError::new(format!(
+                            "Collection '{}': element at index {} is NaN",
+                            name, index
+                        )));
+                    }
+                    self.require_ordered(name, SortOrder::Ascending)
+                }
+            }
+        )*
+    };
+}
+
+impl_float_slice_argument!(f32, f64);
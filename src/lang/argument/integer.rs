@@ -0,0 +1,430 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Integer Argument Validation
+//!
+//! Provides validation functionality specific to primitive integer type arguments.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use super::{
+    error::{
+        ArgumentError,
+        ArgumentResult,
+    },
+    numeric::NumericArgument,
+};
+
+/// Integer argument validation trait
+///
+/// Provides validation methods that only make sense for primitive integer types,
+/// such as validating divisors before an integer division or validating the
+/// result of a checked addition.
+///
+/// # Features
+///
+/// - Prevents the panics of integer division by zero and overflowing division
+/// - Reports overflowing arithmetic as validation errors
+/// - Implemented for all primitive signed and unsigned integer types
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::{IntegerArgument, ArgumentResult};
+///
+/// fn average(total: i64, count: i64) -> ArgumentResult<i64> {
+///     total.checked_div_validated("total", count, "count")
+/// }
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+pub trait IntegerArgument: NumericArgument {
+    /// Validate that value can be used as a divisor, i.e. is not zero
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if value is not zero, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::IntegerArgument;
+    ///
+    /// assert_eq!(4i32.require_valid_divisor("divisor").unwrap(), 4);
+    /// assert!(0i32.require_valid_divisor("divisor").is_err());
+    /// ```
+    fn require_valid_divisor(self, name: &str) -> ArgumentResult<Self>;
+
+    /// Divide value by a divisor, validating that the division cannot panic
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name of the dividend
+    /// * `divisor` - Divisor value
+    /// * `divisor_name` - Parameter name of the divisor
+    ///
+    /// # Returns
+    ///
+    /// Returns the quotient if the divisor is not zero and the division does not
+    /// overflow (e.g. `i32::MIN / -1`), otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::IntegerArgument;
+    ///
+    /// assert_eq!(7i32.checked_div_validated("a", 2, "b").unwrap(), 3);
+    /// assert!(i32::MIN.checked_div_validated("a", -1, "b").is_err());
+    /// ```
+    fn checked_div_validated(
+        self,
+        name: &str,
+        divisor: Self,
+        divisor_name: &str,
+    ) -> ArgumentResult<Self>;
+
+    /// Add a value and validate that the sum is within closed interval
+    ///
+    /// The addition is checked, so an overflowing sum is reported as an error
+    /// instead of panicking or wrapping around.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - Value to add
+    /// * `name` - Parameter name of the sum
+    /// * `min` - Minimum value of the sum (inclusive)
+    /// * `max` - Maximum value of the sum (inclusive)
+    ///
+    /// # Returns
+    ///
+    /// Returns the sum if it does not overflow and is within [min, max] range,
+    /// otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::IntegerArgument;
+    ///
+    /// assert_eq!(i32::require_sum_in_range(40, 2, "total", 0, 100).unwrap(), 42);
+    /// assert!(i32::require_sum_in_range(90, 20, "total", 0, 100).is_err());
+    /// assert!(i32::require_sum_in_range(i32::MAX, 1, "total", 0, i32::MAX).is_err());
+    /// ```
+    fn require_sum_in_range(
+        self,
+        other: Self,
+        name: &str,
+        min: Self,
+        max: Self,
+    ) -> ArgumentResult<Self>;
+}
+
+macro_rules! impl_integer_argument {
+    ($($int:ty),* $(,)?) => {
+        $(
+            impl IntegerArgument for $int {
+                fn require_valid_divisor(self, name: &str) -> ArgumentResult<Self> {
+                    if self == 0 {
+                        return Err(ArgumentError::for_param(name, "cannot be used as a divisor because it is zero"));
+                    }
+                    Ok(self)
+                }
+
+                fn checked_div_validated(
+                    self,
+                    name: &str,
+                    divisor: Self,
+                    divisor_name: &str,
+                ) -> ArgumentResult<Self> {
+                    divisor.require_valid_divisor(divisor_name)?;
+                    self.checked_div(divisor).ok_or_else(|| {
+                        ArgumentError::for_param(
+                            name,
+                            &format!(
+                                "({}) overflows when divided by parameter '{}' ({})",
+                                self, divisor_name, divisor
+                            ),
+                        )
+                    })
+                }
+
+                fn require_sum_in_range(
+                    self,
+                    other: Self,
+                    name: &str,
+                    min: Self,
+                    max: Self,
+                ) -> ArgumentResult<Self> {
+                    let sum = self.checked_add(other).ok_or_else(|| {
+                        ArgumentError::for_param(name, &format!("overflows: {} + {}", self, other))
+                    })?;
+                    sum.require_in_closed_range(name, min, max)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_argument!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Divide two integer arguments, validating that the division cannot panic
+///
+/// # Parameters
+///
+/// * `numerator` - Dividend value
+/// * `name_num` - Dividend parameter name
+/// * `denominator` - Divisor value
+/// * `name_den` - Divisor parameter name
+///
+/// # Returns
+///
+/// Returns the quotient if the divisor is not zero and the division does not
+/// overflow, otherwise returns an error
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::checked_div_validated;
+///
+/// assert_eq!(checked_div_validated(10, "total", 3, "count").unwrap(), 3);
+/// assert!(checked_div_validated(10, "total", 0, "count").is_err());
+/// assert!(checked_div_validated(i32::MIN, "total", -1, "count").is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub fn checked_div_validated<T>(
+    numerator: T,
+    name_num: &str,
+    denominator: T,
+    name_den: &str,
+) -> ArgumentResult<T>
+where
+    T: IntegerArgument,
+{
+    numerator.checked_div_validated(name_num, denominator, name_den)
+}
+
+/// Signed integer argument validation trait
+///
+/// Provides validation methods that only make sense for signed primitive integer
+/// types, such as validating and converting to the matching unsigned type.
+///
+/// # Features
+///
+/// - Fuses validation with safe sign conversion
+/// - Implemented for `i8`, `i16`, `i32`, `i64`, `i128` and `isize`
+/// - Reuses the error messages of `NumericArgument`
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::{SignedIntegerArgument, ArgumentResult};
+///
+/// fn allocate(count: i32) -> ArgumentResult<Vec<u8>> {
+///     let count = count.require_non_negative_as_unsigned("count")?;
+///     Ok(vec![0; count as usize])
+/// }
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+pub trait SignedIntegerArgument: NumericArgument {
+    /// The unsigned integer type of the same width
+    type Unsigned;
+
+    /// Validate that value is non-negative and convert it to the unsigned type
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns the value as the unsigned integer type of the same width if it is
+    /// non-negative, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::SignedIntegerArgument;
+    ///
+    /// assert_eq!(5i32.require_non_negative_as_unsigned("x").unwrap(), 5u32);
+    /// assert!((-1i32).require_non_negative_as_unsigned("x").is_err());
+    /// ```
+    fn require_non_negative_as_unsigned(self, name: &str) -> ArgumentResult<Self::Unsigned>;
+
+    /// Validate that value is non-negative or equal to a sentinel value
+    ///
+    /// Models optional integer parameters of C-style APIs, where a sentinel such
+    /// as `-1` means "unset" and any other value must be non-negative.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `sentinel` - Value that is accepted even if it is negative
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if value equals the sentinel or is non-negative,
+    /// otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::SignedIntegerArgument;
+    ///
+    /// assert!((-1i32).require_non_negative_or("fd", -1).is_ok());
+    /// assert!(3i32.require_non_negative_or("fd", -1).is_ok());
+    /// assert!((-2i32).require_non_negative_or("fd", -1).is_err());
+    /// ```
+    fn require_non_negative_or(self, name: &str, sentinel: Self) -> ArgumentResult<Self>;
+}
+
+macro_rules! impl_signed_integer_argument {
+    ($($signed:ty => $unsigned:ty),* $(,)?) => {
+        $(
+            impl SignedIntegerArgument for $signed {
+                type Unsigned = $unsigned;
+
+                fn require_non_negative_as_unsigned(
+                    self,
+                    name: &str,
+                ) -> ArgumentResult<Self::Unsigned> {
+                    // `unsigned_abs` is lossless once the value is known to be non-negative
+                    Ok(self.require_non_negative(name)?.unsigned_abs())
+                }
+
+                fn require_non_negative_or(self, name: &str, sentinel: Self) -> ArgumentResult<Self> {
+                    if self != sentinel && self < 0 {
+                        return Err(ArgumentError::for_param(
+                            name,
+                            &format!("must be non-negative or {} but was: {}", sentinel, self),
+                        ));
+                    }
+                    Ok(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_signed_integer_argument!(
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize,
+);
+
+/// Integer slice argument validation trait
+///
+/// Provides validation methods for slices of signed primitive integers, such as
+/// sequences of timestamps.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::{IntegerSliceArgument, ArgumentResult};
+///
+/// fn max_gap(timestamps: &[i64]) -> ArgumentResult<Option<i64>> {
+///     let gaps = timestamps.require_non_decreasing_with_gaps("timestamps")?;
+///     Ok(gaps.into_iter().max())
+/// }
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+pub trait IntegerSliceArgument {
+    /// The element type of the slice
+    type Element;
+
+    /// Validate that the elements are non-decreasing and return the gaps between them
+    ///
+    /// Validation and the computation of the successive differences are done in a
+    /// single pass.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Collection name
+    ///
+    /// # Returns
+    ///
+    /// Returns the differences between successive elements, which has one element
+    /// less than the slice, if the elements are non-decreasing. Otherwise returns an
+    /// error at the first decrease, or if a difference overflows the element type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::IntegerSliceArgument;
+    ///
+    /// let timestamps = [100i64, 150, 150, 400];
+    /// assert_eq!(
+    ///     timestamps.require_non_decreasing_with_gaps("timestamps").unwrap(),
+    ///     vec![50, 0, 250]
+    /// );
+    /// assert!([100i64, 90].require_non_decreasing_with_gaps("timestamps").is_err());
+    /// ```
+    fn require_non_decreasing_with_gaps(&self, name: &str) -> ArgumentResult<Vec<Self::Element>>;
+}
+
+macro_rules! impl_integer_slice_argument {
+    ($($int:ty),* $(,)?) => {
+        $(
+            impl IntegerSliceArgument for [$int] {
+                type Element = $int;
+
+                fn require_non_decreasing_with_gaps(
+                    &self,
+                    name: &str,
+                ) -> ArgumentResult<Vec<Self::Element>> {
+                    let mut gaps = Vec::with_capacity(self.len().saturating_sub(1));
+                    for (index, pair) in self.windows(2).enumerate() {
+                        if pair[1] < pair[0] {
+                            return Err(ArgumentError::new(format!(
+                                "Collection '{}' must be non-decreasing but element at index {} ({}) is less than element at index {} ({})",
+                                name,
+                                index + 1,
+                                pair[1],
+                                index,
+                                pair[0]
+                            )));
+                        }
+                        let gap = pair[1].checked_sub(pair[0]).ok_or_else(|| {
+                            ArgumentError::new(format!(
+                                "Collection '{}': gap between elements at indices {} and {} overflows",
+                                name,
+                                index,
+                                index + 1
+                            ))
+                        })?;
+                        gaps.push(gap);
+                    }
+                    Ok(gaps)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_slice_argument!(i8, i16, i32, i64, i128, isize);
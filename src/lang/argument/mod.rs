@@ -14,10 +14,17 @@
 //!
 //! - `error`: Error type definitions
 //! - `numeric`: Numeric argument validation
+//! - `bounded`: Values validated against a closed range at construction
+//! - `integer`: Integer-specific argument validation
+//! - `float`: Floating-point-specific argument validation
 //! - `string`: String argument validation
 //! - `collection`: Collection argument validation
+//! - `string_collection`: String collection argument validation
 //! - `option`: Option argument validation
+//! - `path`: Filesystem path argument validation
 //! - `condition`: Condition and state validation
+//! - `temporal`: Date and time argument validation
+//! - `typed_error`: Pattern-matchable error type (requires the `typed-error` feature)
 //!
 //! # Design Philosophy
 //!
@@ -60,17 +67,34 @@
 //!
 //! Haixing Hu
 
+pub mod bounded;
 pub mod collection;
 pub mod condition;
 pub mod error;
+pub mod float;
+pub mod integer;
 pub mod numeric;
 pub mod option;
+pub mod path;
 pub mod string;
+pub mod string_collection;
+pub mod temporal;
+#[cfg(feature = "typed-error")]
+pub mod typed_error;
 
 // Re-export main types and traits
+pub use bounded::Bounded;
 pub use collection::{
+    require_all_some,
     require_element_non_null,
+    require_length_in_range_owned,
+    require_length_relation,
+    require_member,
+    require_non_empty_owned,
+    require_same_length,
     CollectionArgument,
+    SortOrder,
+    VecArgument,
 };
 pub use condition::{
     check_argument,
@@ -82,18 +106,50 @@ pub use condition::{
     check_position_indexes,
     check_state,
     check_state_with_message,
+    check_that,
+    get_checked,
+    require,
 };
 pub use error::{
+    group_by_parameter,
+    zip_results3,
     ArgumentError,
     ArgumentResult,
+    ArgumentResultExt,
+};
+pub use float::{
+    FloatArgument,
+    FloatSliceArgument,
+};
+pub use integer::{
+    checked_div_validated,
+    IntegerArgument,
+    IntegerSliceArgument,
+    SignedIntegerArgument,
 };
 pub use numeric::{
+    parse_named,
     require_equal,
+    require_equal_within,
     require_not_equal,
+    require_ordered,
+    require_sum_approx_equals,
+    require_sum_equals,
+    CheckedArithmetic,
     NumericArgument,
 };
 pub use option::{
+    from_option,
     require_null_or,
     OptionArgument,
 };
-pub use string::StringArgument;
+pub use path::PathArgument;
+pub use string::{
+    require_enum,
+    NonBlankString,
+    StringArgument,
+};
+pub use string_collection::StringCollectionArgument;
+pub use temporal::require_date_in_range;
+#[cfg(feature = "typed-error")]
+pub use typed_error::TypedArgumentError;
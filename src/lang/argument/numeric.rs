@@ -18,7 +18,11 @@ use super::error::{
     ArgumentError,
     ArgumentResult,
 };
+use crate::util::Pair;
+use std::any::type_name;
+use std::cmp::Ordering;
 use std::fmt::Display;
+use std::str::FromStr;
 
 /// Numeric argument validation trait
 ///
@@ -407,6 +411,71 @@ pub trait NumericArgument: Sized {
     /// assert!(under.require_greater_equal("value", 0).is_err());
     /// ```
     fn require_greater_equal(self, name: &str, min: Self) -> ArgumentResult<Self>;
+
+    /// Validate that value is within closed interval, validating the bounds first
+    ///
+    /// Unlike `require_in_closed_range`, inverted bounds (`min > max`) are reported
+    /// with a distinct invalid bounds error instead of a misleading out of range
+    /// error, which helps to detect swapped arguments.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `min` - Minimum value (inclusive)
+    /// * `max` - Maximum value (inclusive)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if `min <= max` and value is within [min, max] range,
+    /// otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::NumericArgument;
+    ///
+    /// assert!(5.require_in_closed_range_checked("value", 1, 10).is_ok());
+    ///
+    /// let error = 5.require_in_closed_range_checked("value", 10, 1).unwrap_err();
+    /// assert!(error.message().contains("invalid bounds"));
+    /// ```
+    fn require_in_closed_range_checked(
+        self,
+        name: &str,
+        min: Self,
+        max: Self,
+    ) -> ArgumentResult<Self>;
+
+    /// Validate that value is within open interval, reporting which bound failed
+    ///
+    /// Accepts the same values as `require_in_open_range`, but the error message
+    /// names the violated bound instead of the whole interval, which gives users
+    /// more actionable feedback. Values that cannot be compared with the bounds
+    /// (e.g. NaN) are rejected.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `min` - Minimum value (exclusive)
+    /// * `max` - Maximum value (exclusive)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if value is within (min, max) range, otherwise returns an
+    /// error stating that value must be greater than `min` or less than `max`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::NumericArgument;
+    ///
+    /// assert!(50.require_within_open_interval("value", 0, 100).is_ok());
+    ///
+    /// let error = 0.require_within_open_interval("value", 0, 100).unwrap_err();
+    /// assert_eq!(error.message(), "Parameter 'value' must be greater than 0 but was: 0");
+    /// ```
+    fn require_within_open_interval(self, name: &str, min: Self, max: Self)
+        -> ArgumentResult<Self>;
 }
 
 /// Implement numeric argument validation for all ordered displayable types
@@ -419,145 +488,248 @@ where
 {
     fn require_zero(self, name: &str) -> ArgumentResult<Self> {
         if self != T::default() {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' must be zero but was: {}",
-                name, self
-            )));
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must be zero but was: {}", self),
+            ));
         }
         Ok(self)
     }
 
     fn require_non_zero(self, name: &str) -> ArgumentResult<Self> {
         if self == T::default() {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' cannot be zero",
-                name
-            )));
+            return Err(ArgumentError::for_param(name, "cannot be zero"));
         }
         Ok(self)
     }
 
     fn require_positive(self, name: &str) -> ArgumentResult<Self> {
         if self <= T::default() {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' must be positive but was: {}",
-                name, self
-            )));
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must be positive but was: {}", self),
+            ));
         }
         Ok(self)
     }
 
     fn require_non_negative(self, name: &str) -> ArgumentResult<Self> {
         if self < T::default() {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' must be non-negative but was: {}",
-                name, self
-            )));
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must be non-negative but was: {}", self),
+            ));
         }
         Ok(self)
     }
 
     fn require_negative(self, name: &str) -> ArgumentResult<Self> {
         if self >= T::default() {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' must be negative but was: {}",
-                name, self
-            )));
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must be negative but was: {}", self),
+            ));
         }
         Ok(self)
     }
 
     fn require_non_positive(self, name: &str) -> ArgumentResult<Self> {
         if self > T::default() {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' must be non-positive but was: {}",
-                name, self
-            )));
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must be non-positive but was: {}", self),
+            ));
         }
         Ok(self)
     }
 
     fn require_in_closed_range(self, name: &str, min: Self, max: Self) -> ArgumentResult<Self> {
         if self < min || self > max {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' must be in range [{}, {}] but was: {}",
-                name, min, max, self
-            )));
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must be in range [{}, {}] but was: {}", min, max, self),
+            ));
         }
         Ok(self)
     }
 
     fn require_in_open_range(self, name: &str, min: Self, max: Self) -> ArgumentResult<Self> {
         if self <= min || self >= max {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' must be in range ({}, {}) but was: {}",
-                name, min, max, self
-            )));
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must be in range ({}, {}) but was: {}", min, max, self),
+            ));
         }
         Ok(self)
     }
 
     fn require_in_left_open_range(self, name: &str, min: Self, max: Self) -> ArgumentResult<Self> {
         if self <= min || self > max {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' must be in range ({}, {}] but was: {}",
-                name, min, max, self
-            )));
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must be in range ({}, {}] but was: {}", min, max, self),
+            ));
         }
         Ok(self)
     }
 
     fn require_in_right_open_range(self, name: &str, min: Self, max: Self) -> ArgumentResult<Self> {
         if self < min || self >= max {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' must be in range [{}, {}) but was: {}",
-                name, min, max, self
-            )));
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must be in range [{}, {}) but was: {}", min, max, self),
+            ));
         }
         Ok(self)
     }
 
     fn require_less(self, name: &str, max: Self) -> ArgumentResult<Self> {
         if self >= max {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' must be less than {} but was: {}",
-                name, max, self
-            )));
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must be less than {} but was: {}", max, self),
+            ));
         }
         Ok(self)
     }
 
     fn require_less_equal(self, name: &str, max: Self) -> ArgumentResult<Self> {
         if self > max {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' must be less than or equal to {} but was: {}",
-                name, max, self
-            )));
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must be less than or equal to {} but was: {}", max, self),
+            ));
         }
         Ok(self)
     }
 
     fn require_greater(self, name: &str, min: Self) -> ArgumentResult<Self> {
         if self <= min {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' must be greater than {} but was: {}",
-                name, min, self
-            )));
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must be greater than {} but was: {}", min, self),
+            ));
         }
         Ok(self)
     }
 
     fn require_greater_equal(self, name: &str, min: Self) -> ArgumentResult<Self> {
         if self < min {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' must be greater than or equal to {} but was: {}",
-                name, min, self
-            )));
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must be greater than or equal to {} but was: {}", min, self),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_in_closed_range_checked(
+        self,
+        name: &str,
+        min: Self,
+        max: Self,
+    ) -> ArgumentResult<Self> {
+        if min > max {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "has invalid bounds: minimum {} is greater than maximum {}",
+                    min, max
+                ),
+            ));
+        }
+        self.require_in_closed_range(name, min, max)
+    }
+
+    fn require_within_open_interval(
+        self,
+        name: &str,
+        min: Self,
+        max: Self,
+    ) -> ArgumentResult<Self> {
+        if self.partial_cmp(&min) != Some(Ordering::Greater) {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must be greater than {} but was: {}", min, self),
+            ));
+        }
+        if self.partial_cmp(&max) != Some(Ordering::Less) {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must be less than {} but was: {}", max, self),
+            ));
         }
         Ok(self)
     }
 }
 
+/// Checked arithmetic on primitive numbers
+///
+/// Abstracts over the inherent `checked_add` and `checked_sub` methods of the
+/// primitive integer types, so that generic validators can report an overflow as
+/// a validation error instead of panicking. Floating-point arithmetic never fails:
+/// an overflowing result is infinite and is rejected by the comparisons of the
+/// validators.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::CheckedArithmetic;
+///
+/// assert_eq!(CheckedArithmetic::checked_add(i32::MAX, 1), None);
+/// assert_eq!(CheckedArithmetic::checked_sub(5u8, 3), Some(2));
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+pub trait CheckedArithmetic: Copy {
+    /// Add two values, returning `None` on overflow
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Subtract a value, returning `None` on overflow
+    fn checked_sub(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_arithmetic_for_integer {
+    ($($int:ty),* $(,)?) => {
+        $(
+            impl CheckedArithmetic for $int {
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$int>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$int>::checked_sub(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_arithmetic_for_integer!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+macro_rules! impl_checked_arithmetic_for_float {
+    ($($float:ty),* $(,)?) => {
+        $(
+            impl CheckedArithmetic for $float {
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    Some(self + other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    Some(self - other)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_arithmetic_for_float!(f32, f64);
+
 /// Comparison argument validation
 ///
 /// Provides comparison validation functionality between two arguments.
@@ -582,10 +754,10 @@ where
     T: PartialEq + Display,
 {
     if value1 != value2 {
-        return Err(ArgumentError::new(format!(
-            "Parameter '{}' ({}) must equal parameter '{}' ({})",
-            name1, value1, name2, value2
-        )));
+        return Err(ArgumentError::for_param(
+            name1,
+            &format!("({}) must equal parameter '{}' ({})", value1, name2, value2),
+        ));
     }
     Ok(())
 }
@@ -623,3 +795,279 @@ where
     }
     Ok(())
 }
+
+/// Validate that two arguments are equal within a tolerance
+///
+/// This is the tolerance-aware counterpart of `require_equal` for ordered numeric
+/// types. It works for both integers and floating-point numbers, including
+/// unsigned integers, since the difference is always computed by subtracting the
+/// smaller value from the larger one. A `NaN` difference never matches.
+///
+/// # Parameters
+///
+/// * `name1` - First parameter name
+/// * `value1` - First parameter value
+/// * `name2` - Second parameter name
+/// * `value2` - Second parameter value
+/// * `tolerance` - Maximum allowed absolute difference between the values
+///
+/// # Returns
+///
+/// Returns `Ok(())` if the values differ by at most `tolerance`, otherwise returns
+/// an error. Values whose difference overflows `T`, such as `i32::MIN` and
+/// `i32::MAX`, are never equal within any tolerance.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::require_equal_within;
+///
+/// assert!(require_equal_within("expected", 100u32, "actual", 98, 2).is_ok());
+/// assert!(require_equal_within("expected", 1.0, "actual", 1.5, 0.1).is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub fn require_equal_within<T>(
+    name1: &str,
+    value1: T,
+    name2: &str,
+    value2: T,
+    tolerance: T,
+) -> ArgumentResult<()>
+where
+    T: CheckedArithmetic + PartialOrd + Display,
+{
+    // Overflowing and incomparable (e.g. NaN) differences are treated as mismatches
+    let within = abs_diff(value1, value2).is_some_and(|diff| {
+        matches!(
+            diff.partial_cmp(&tolerance),
+            Some(Ordering::Less | Ordering::Equal)
+        )
+    });
+    if !within {
+        return Err(ArgumentError::for_param(
+            name1,
+            &format!(
+                "({}) must equal parameter '{}' ({}) within {}",
+                value1, name2, value2, tolerance
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the absolute difference of two values, or `None` if it overflows `T`
+///
+/// Subtracting the smaller value from the larger one avoids underflowing unsigned
+/// types.
+fn abs_diff<T>(a: T, b: T) -> Option<T>
+where
+    T: CheckedArithmetic + PartialOrd,
+{
+    if a >= b {
+        a.checked_sub(b)
+    } else {
+        b.checked_sub(a)
+    }
+}
+
+/// Validate that two arguments are in non-decreasing order
+///
+/// Checks that `lo <= hi` and returns both values as a `Pair`, which is convenient
+/// for range constructors taking a lower and an upper bound.
+///
+/// # Parameters
+///
+/// * `name_lo` - Lower bound parameter name
+/// * `lo` - Lower bound value
+/// * `name_hi` - Upper bound parameter name
+/// * `hi` - Upper bound value
+///
+/// # Returns
+///
+/// Returns `Ok(Pair::new(lo, hi))` if `lo <= hi`, otherwise returns an error
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::{lang::argument::require_ordered, Pair};
+///
+/// assert_eq!(require_ordered("min", 1, "max", 10).unwrap(), Pair::new(1, 10));
+/// assert!(require_ordered("min", 10, "max", 1).is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub fn require_ordered<T>(name_lo: &str, lo: T, name_hi: &str, hi: T) -> ArgumentResult<Pair<T, T>>
+where
+    T: PartialOrd + Display,
+{
+    // Incomparable values (e.g. NaN) are treated as out of order
+    if !matches!(lo.partial_cmp(&hi), Some(Ordering::Less | Ordering::Equal)) {
+        return Err(ArgumentError::for_param(
+            name_lo,
+            &format!(
+                "({}) must be less than or equal to parameter '{}' ({})",
+                lo, name_hi, hi
+            ),
+        ));
+    }
+    Ok(Pair::new(lo, hi))
+}
+
+/// Validate that numbers add up to an expected total
+///
+/// Useful for allocation percentages that must sum to 100. For floating-point
+/// values, whose sum is subject to rounding errors, use
+/// `require_sum_approx_equals` instead.
+///
+/// # Parameters
+///
+/// * `name` - Collection name
+/// * `values` - Values to add up
+/// * `expected` - Expected total
+///
+/// # Returns
+///
+/// Returns `Ok(())` if the values sum to the expected total, otherwise returns an
+/// error. An integer sum that overflows `T` is reported as an error as well.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::require_sum_equals;
+///
+/// assert!(require_sum_equals("shares", &[50, 30, 20], 100).is_ok());
+/// assert!(require_sum_equals("shares", &[50, 30, 15], 100).is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub fn require_sum_equals<T>(name: &str, values: &[T], expected: T) -> ArgumentResult<()>
+where
+    T: CheckedArithmetic + Default + PartialEq + Display,
+{
+    let sum = checked_sum(name, values)?;
+    if sum != expected {
+        return Err(ArgumentError::new(format!(
+            "Values in '{}' must sum to {} but summed to {}",
+            name, expected, sum
+        )));
+    }
+    Ok(())
+}
+
+/// Validate that numbers add up to an expected total within a tolerance
+///
+/// This is the floating-point variant of `require_sum_equals`, e.g. for weights
+/// that must sum to `1.0`. A `NaN` sum never matches.
+///
+/// # Parameters
+///
+/// * `name` - Collection name
+/// * `values` - Values to add up
+/// * `expected` - Expected total
+/// * `epsilon` - Maximum allowed absolute difference between the sum and the total
+///
+/// # Returns
+///
+/// Returns `Ok(())` if the sum differs from the expected total by at most `epsilon`,
+/// otherwise returns an error. An integer sum that overflows `T` is reported as an
+/// error as well.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::require_sum_approx_equals;
+///
+/// assert!(require_sum_approx_equals("weights", &[0.1, 0.2, 0.7], 1.0, 1e-9).is_ok());
+/// assert!(require_sum_approx_equals("weights", &[0.1, 0.2, 0.6], 1.0, 1e-9).is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub fn require_sum_approx_equals<T>(
+    name: &str,
+    values: &[T],
+    expected: T,
+    epsilon: T,
+) -> ArgumentResult<()>
+where
+    T: CheckedArithmetic + Default + PartialOrd + Display,
+{
+    let sum = checked_sum(name, values)?;
+    // Overflowing and incomparable (e.g. NaN) differences are treated as mismatches
+    let within = abs_diff(sum, expected).is_some_and(|diff| {
+        matches!(
+            diff.partial_cmp(&epsilon),
+            Some(Ordering::Less | Ordering::Equal)
+        )
+    });
+    if !within {
+        return Err(ArgumentError::new(format!(
+            "Values in '{}' must sum to {} (within {}) but summed to {}",
+            name, expected, epsilon, sum
+        )));
+    }
+    Ok(())
+}
+
+/// Adds up the values, reporting an overflowing sum as an error
+fn checked_sum<T>(name: &str, values: &[T]) -> ArgumentResult<T>
+where
+    T: CheckedArithmetic + Default,
+{
+    values.iter().try_fold(T::default(), |sum, value| {
+        sum.checked_add(*value)
+            .ok_or_else(|| ArgumentError::new(format!("Values in '{}' overflow when summed", name)))
+    })
+}
+
+/// Parse a string argument, attaching the parameter name to parse errors
+///
+/// Bridges `str::parse` and the argument error type: the error of the `FromStr`
+/// implementation is replaced with a message naming the parameter and the type
+/// that was expected, e.g. `Parameter 'count' is not a valid i32: 'x'`. The type is
+/// named by `std::any::type_name`, so the wording is meant for humans and is not
+/// guaranteed to be stable across compiler versions.
+///
+/// # Parameters
+///
+/// * `name` - Parameter name
+/// * `s` - String to parse
+///
+/// # Returns
+///
+/// Returns the parsed value, or an error if `s` cannot be parsed as `T`
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::parse_named;
+///
+/// assert_eq!(parse_named::<i32>("count", "42").unwrap(), 42);
+/// assert_eq!(
+///     parse_named::<i32>("count", "x").unwrap_err().message(),
+///     "Parameter 'count' is not a valid i32: 'x'"
+/// );
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub fn parse_named<T>(name: &str, s: &str) -> ArgumentResult<T>
+where
+    T: FromStr,
+{
+    s.parse().map_err(|_| {
+        ArgumentError::for_param(
+            name,
+            &format!("is not a valid {}: '{}'", type_name::<T>(), s),
+        )
+    })
+}
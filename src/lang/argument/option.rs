@@ -160,10 +160,7 @@ impl<T> OptionArgument<T> for Option<T> {
     fn require_non_null(self, name: &str) -> ArgumentResult<T> {
         match self {
             Some(value) => Ok(value),
-            None => {
-                let message = format!("Parameter '{}' cannot be null", name);
-                Err(ArgumentError::new(message))
-            }
+            None => Err(ArgumentError::for_param(name, "cannot be null")),
         }
     }
 
@@ -176,14 +173,10 @@ impl<T> OptionArgument<T> for Option<T> {
                 if predicate(&value) {
                     Ok(value)
                 } else {
-                    let message = format!("Parameter '{}' {}", name, error_msg);
-                    Err(ArgumentError::new(message))
+                    Err(ArgumentError::for_param(name, error_msg))
                 }
             }
-            None => {
-                let message = format!("Parameter '{}' cannot be null", name);
-                Err(ArgumentError::new(message))
-            }
+            None => Err(ArgumentError::for_param(name, "cannot be null")),
         }
     }
 
@@ -247,12 +240,41 @@ where
         None => Ok(None),
         Some(ref v) => {
             if !predicate(v) {
-                return Err(ArgumentError::new(format!(
-                    "Parameter '{}' {}",
-                    name, error_msg
-                )));
+                return Err(ArgumentError::for_param(name, error_msg));
             }
             Ok(value)
         }
     }
 }
+
+/// Convert an Option into an argument validation result
+///
+/// This is the free function form of `OptionArgument::require_non_null`, which
+/// reads naturally where an `Option` returned by another API is converted into
+/// the crate's result type.
+///
+/// # Parameters
+///
+/// * `name` - Parameter name
+/// * `value` - Option value to convert
+///
+/// # Returns
+///
+/// Returns `Ok(value)` if Some(value), otherwise returns an error
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::from_option;
+///
+/// assert_eq!(from_option("port", Some(8080)).unwrap(), 8080);
+/// assert!(from_option::<u16>("port", None).is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+pub fn from_option<T>(name: &str, value: Option<T>) -> ArgumentResult<T> {
+    value.require_non_null(name)
+}
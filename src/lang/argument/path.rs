@@ -0,0 +1,206 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Path Argument Validation
+//!
+//! Provides validation functionality for filesystem path arguments.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use super::error::{
+    ArgumentError,
+    ArgumentResult,
+};
+use std::{
+    ffi::OsStr,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+/// Path argument validation trait
+///
+/// Provides validation methods for `Path` and `PathBuf` arguments. All checks are
+/// purely lexical and never access the filesystem.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::{PathArgument, ArgumentResult};
+/// use std::path::Path;
+///
+/// fn load_config(path: &Path) -> ArgumentResult<()> {
+///     path.require_non_empty("path")?
+///         .require_is_relative("path")?
+///         .require_extension("path", "toml")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+pub trait PathArgument {
+    /// Validate that the path is not empty
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the path is not empty, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::PathArgument;
+    /// use std::path::Path;
+    ///
+    /// assert!(Path::new("a.txt").require_non_empty("file").is_ok());
+    /// assert!(Path::new("").require_non_empty("file").is_err());
+    /// ```
+    fn require_non_empty(&self, name: &str) -> ArgumentResult<&Self>;
+
+    /// Validate that the path has the given extension
+    ///
+    /// The comparison is case-sensitive.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `ext` - Expected extension, without the leading dot
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the extension of the path equals `ext`, otherwise
+    /// returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::PathArgument;
+    /// use std::path::Path;
+    ///
+    /// assert!(Path::new("a/b.txt").require_extension("f", "txt").is_ok());
+    /// assert!(Path::new("a/b.md").require_extension("f", "txt").is_err());
+    /// ```
+    fn require_extension(&self, name: &str, ext: &str) -> ArgumentResult<&Self>;
+
+    /// Validate that the path is relative
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the path is relative, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::PathArgument;
+    /// use std::path::Path;
+    ///
+    /// assert!(Path::new("a/b.txt").require_is_relative("file").is_ok());
+    /// ```
+    fn require_is_relative(&self, name: &str) -> ArgumentResult<&Self>;
+
+    /// Validate that the path is absolute
+    ///
+    /// What counts as an absolute path is platform-specific, see
+    /// `Path::is_absolute`.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the path is absolute, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::PathArgument;
+    /// use std::path::Path;
+    ///
+    /// assert!(Path::new("a/b.txt").require_is_absolute("file").is_err());
+    /// ```
+    fn require_is_absolute(&self, name: &str) -> ArgumentResult<&Self>;
+}
+
+impl PathArgument for Path {
+    fn require_non_empty(&self, name: &str) -> ArgumentResult<&Self> {
+        if self.as_os_str().is_empty() {
+            return Err(ArgumentError::for_param(name, "cannot be an empty path"));
+        }
+        Ok(self)
+    }
+
+    fn require_extension(&self, name: &str, ext: &str) -> ArgumentResult<&Self> {
+        if self.extension() != Some(OsStr::new(ext)) {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "must have extension '{}' but was: '{}'",
+                    ext,
+                    self.display()
+                ),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_is_relative(&self, name: &str) -> ArgumentResult<&Self> {
+        if !self.is_relative() {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must be a relative path but was: '{}'", self.display()),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_is_absolute(&self, name: &str) -> ArgumentResult<&Self> {
+        if !self.is_absolute() {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must be an absolute path but was: '{}'", self.display()),
+            ));
+        }
+        Ok(self)
+    }
+}
+
+impl PathArgument for PathBuf {
+    fn require_non_empty(&self, name: &str) -> ArgumentResult<&Self> {
+        self.as_path().require_non_empty(name)?;
+        Ok(self)
+    }
+
+    fn require_extension(&self, name: &str, ext: &str) -> ArgumentResult<&Self> {
+        self.as_path().require_extension(name, ext)?;
+        Ok(self)
+    }
+
+    fn require_is_relative(&self, name: &str) -> ArgumentResult<&Self> {
+        self.as_path().require_is_relative(name)?;
+        Ok(self)
+    }
+
+    fn require_is_absolute(&self, name: &str) -> ArgumentResult<&Self> {
+        self.as_path().require_is_absolute(name)?;
+        Ok(self)
+    }
+}
@@ -18,7 +18,15 @@ use super::error::{
     ArgumentError,
     ArgumentResult,
 };
+use crate::util::patterns;
 use regex::Regex;
+use std::cmp::Ordering;
+use std::fmt::{
+    self,
+    Display,
+};
+use std::ops::Deref;
+use std::str::FromStr;
 
 /// String argument validation trait
 ///
@@ -97,6 +105,29 @@ pub trait StringArgument {
     /// ```
     fn require_non_blank(&self, name: &str) -> ArgumentResult<&Self>;
 
+    /// Validate that string is not empty
+    ///
+    /// Unlike `require_non_blank`, a string consisting only of whitespace characters
+    /// is accepted, which matters when whitespace is meaningful content.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if string is not empty, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// assert!("   ".require_non_empty("separator").is_ok());
+    /// assert!("".require_non_empty("separator").is_err());
+    /// ```
+    fn require_non_empty(&self, name: &str) -> ArgumentResult<&Self>;
+
     /// Validate that string length equals the specified value
     ///
     /// # Parameters
@@ -235,170 +266,1392 @@ pub trait StringArgument {
     /// assert!(text.require_not_match("text", &pattern).is_ok());
     /// ```
     fn require_not_match(&self, name: &str, pattern: &Regex) -> ArgumentResult<&Self>;
-}
-
-impl StringArgument for str {
-    fn require_non_blank(&self, name: &str) -> ArgumentResult<&Self> {
-        if self.trim().is_empty() {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' cannot be empty or contain only whitespace characters",
-                name
-            )));
-        }
-        Ok(self)
-    }
 
-    fn require_length_be(&self, name: &str, length: usize) -> ArgumentResult<&Self> {
-        let actual_length = self.len();
-        if actual_length != length {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' length must be {} but was {}",
-                name, length, actual_length
-            )));
-        }
-        Ok(self)
-    }
+    /// Validate that string is a hexadecimal string of the specified length
+    ///
+    /// Both lowercase and uppercase hexadecimal digits are accepted.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `expected_len` - Expected number of hexadecimal digits
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the string consists of exactly `expected_len`
+    /// hexadecimal digits, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// let hash = "deadBEEF";
+    /// assert!(hash.require_hex_string("hash", 8).is_ok());
+    ///
+    /// let invalid = "xyz";
+    /// assert!(invalid.require_hex_string("hash", 3).is_err());
+    /// ```
+    fn require_hex_string(&self, name: &str, expected_len: usize) -> ArgumentResult<&Self>;
 
-    fn require_length_at_least(&self, name: &str, min_length: usize) -> ArgumentResult<&Self> {
-        let actual_length = self.len();
-        if actual_length < min_length {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' length must be at least {} but was {}",
-                name, min_length, actual_length
-            )));
-        }
-        Ok(self)
-    }
+    /// Validate that string is a hyphenated UUID
+    ///
+    /// Checks the canonical `8-4-4-4-12` structure of hexadecimal digit groups
+    /// separated by hyphens, e.g. `550e8400-e29b-41d4-a716-446655440000`. The
+    /// version and variant bits are not checked.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the string has the UUID format, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// let id = "550e8400-e29b-41d4-a716-446655440000";
+    /// assert!(id.require_uuid_format("id").is_ok());
+    ///
+    /// let invalid = "550e8400e29b41d4a716446655440000";
+    /// assert!(invalid.require_uuid_format("id").is_err());
+    /// ```
+    fn require_uuid_format(&self, name: &str) -> ArgumentResult<&Self>;
 
-    fn require_length_at_most(&self, name: &str, max_length: usize) -> ArgumentResult<&Self> {
-        let actual_length = self.len();
-        if actual_length > max_length {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' length must be at most {} but was {}",
-                name, max_length, actual_length
-            )));
-        }
-        Ok(self)
-    }
+    /// Validate that string ends with one of the specified suffixes
+    ///
+    /// The comparison is case-sensitive; use `require_ends_with_one_of_ignore_case`
+    /// for case-insensitive matching.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `suffixes` - Allowed suffixes
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the string ends with any of the suffixes, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// let filename = "photo.png";
+    /// assert!(filename.require_ends_with_one_of("filename", &[".png", ".jpg"]).is_ok());
+    ///
+    /// let document = "doc.pdf";
+    /// assert!(document.require_ends_with_one_of("filename", &[".png", ".jpg"]).is_err());
+    /// ```
+    fn require_ends_with_one_of(&self, name: &str, suffixes: &[&str]) -> ArgumentResult<&Self>;
 
-    fn require_length_in_range(
+    /// Validate that string ends with one of the specified suffixes, ignoring case
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `suffixes` - Allowed suffixes
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the string ends with any of the suffixes regardless of case,
+    /// otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// let filename = "PHOTO.PNG";
+    /// assert!(filename
+    ///     .require_ends_with_one_of_ignore_case("filename", &[".png", ".jpg"])
+    ///     .is_ok());
+    /// ```
+    fn require_ends_with_one_of_ignore_case(
         &self,
         name: &str,
-        min_length: usize,
-        max_length: usize,
-    ) -> ArgumentResult<&Self> {
-        let actual_length = self.len();
-        if actual_length < min_length || actual_length > max_length {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' length must be in range [{}, {}] but was {}",
-                name, min_length, max_length, actual_length
-            )));
-        }
-        Ok(self)
-    }
-
-    fn require_match(&self, name: &str, pattern: &Regex) -> ArgumentResult<&Self> {
-        if !pattern.is_match(self) {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' must match pattern '{}'",
-                name,
-                pattern.as_str()
-            )));
-        }
-        Ok(self)
-    }
+        suffixes: &[&str],
+    ) -> ArgumentResult<&Self>;
 
-    fn require_not_match(&self, name: &str, pattern: &Regex) -> ArgumentResult<&Self> {
-        if pattern.is_match(self) {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' cannot match pattern '{}'",
-                name,
-                pattern.as_str()
-            )));
-        }
-        Ok(self)
-    }
-}
+    /// Validate that string contains no control characters
+    ///
+    /// Rejects any character for which `char::is_control` returns `true`, such as
+    /// `\n`, `\t`, `\u{7}` (bell) or `\u{1b}` (escape). This guards strings written
+    /// to logs or terminals against escape sequence injection.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the string contains no control characters, otherwise
+    /// returns an error reporting the code point and byte position of the first one
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// assert!("ok".require_no_control_chars("label").is_ok());
+    /// assert!("a\u{7}".require_no_control_chars("label").is_err());
+    /// ```
+    fn require_no_control_chars(&self, name: &str) -> ArgumentResult<&Self>;
 
-impl StringArgument for String {
-    fn require_non_blank(&self, name: &str) -> ArgumentResult<&Self> {
-        if self.trim().is_empty() {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' cannot be empty or contain only whitespace characters",
-                name
-            )));
-        }
-        Ok(self)
-    }
+    /// Validate that string is ASCII and has the specified length
+    ///
+    /// For ASCII strings the byte length equals the character length, which makes
+    /// this suitable for fixed-width field formats. The ASCII check is performed
+    /// first, so a non-ASCII string is always reported as such.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `length` - Expected length
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the string is ASCII with the expected length, otherwise
+    /// returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// assert!("AB12".require_ascii_length_be("code", 4).is_ok());
+    /// assert!("AB1".require_ascii_length_be("code", 4).is_err());
+    /// assert!("ÄB1".require_ascii_length_be("code", 4).is_err());
+    /// ```
+    fn require_ascii_length_be(&self, name: &str, length: usize) -> ArgumentResult<&Self>;
 
-    fn require_length_be(&self, name: &str, length: usize) -> ArgumentResult<&Self> {
-        let actual_length = self.len();
-        if actual_length != length {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' length must be {} but was {}",
-                name, length, actual_length
-            )));
-        }
-        Ok(self)
-    }
+    /// Validate that string does not start with a leading zero
+    ///
+    /// The single digit string `"0"` is accepted; any other string starting with
+    /// `'0'` is rejected. This is useful for numeric strings such as account
+    /// numbers, where leading zeros are not allowed.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the string has no leading zero, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// assert!("0".require_no_leading_zero("amount").is_ok());
+    /// assert!("123".require_no_leading_zero("amount").is_ok());
+    /// assert!("007".require_no_leading_zero("amount").is_err());
+    /// ```
+    fn require_no_leading_zero(&self, name: &str) -> ArgumentResult<&Self>;
 
-    fn require_length_at_least(&self, name: &str, min_length: usize) -> ArgumentResult<&Self> {
-        let actual_length = self.len();
-        if actual_length < min_length {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' length must be at least {} but was {}",
-                name, min_length, actual_length
-            )));
-        }
-        Ok(self)
-    }
+    /// Validate that every character of the string is in the allowed set
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `allowed` - String containing all allowed characters
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if all characters are allowed, otherwise returns an error
+    /// reporting the first disallowed character and its byte position
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// assert!("1010".require_chars_in("bits", "01").is_ok());
+    /// assert!("1020".require_chars_in("bits", "01").is_err());
+    /// ```
+    fn require_chars_in(&self, name: &str, allowed: &str) -> ArgumentResult<&Self>;
 
-    fn require_length_at_most(&self, name: &str, max_length: usize) -> ArgumentResult<&Self> {
-        let actual_length = self.len();
-        if actual_length > max_length {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' length must be at most {} but was {}",
-                name, max_length, actual_length
-            )));
-        }
-        Ok(self)
-    }
+    /// Validate that every character of the string matches a predicate
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `f` - Predicate that returns `true` for allowed characters
+    /// * `desc` - Description of the allowed characters used in the error message
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if all characters match the predicate, otherwise returns
+    /// an error reporting the first mismatching character and its byte position
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// let is_slug_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    /// assert!("abc_1"
+    ///     .require_chars_matching("slug", is_slug_char, "alphanumeric or underscore")
+    ///     .is_ok());
+    /// assert!("abc-1"
+    ///     .require_chars_matching("slug", is_slug_char, "alphanumeric or underscore")
+    ///     .is_err());
+    /// ```
+    fn require_chars_matching<F>(&self, name: &str, f: F, desc: &str) -> ArgumentResult<&Self>
+    where
+        F: Fn(char) -> bool;
 
-    fn require_length_in_range(
+    /// Validate that the trimmed string length does not exceed the maximum
+    ///
+    /// Leading and trailing whitespace is ignored, which is useful for padded input
+    /// where only the meaningful content length is limited.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `max_length` - Maximum length of the trimmed string
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the trimmed length is less than or equal to the maximum,
+    /// otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// assert!("  hello  ".require_trimmed_length_at_most("x", 5).is_ok());
+    /// assert!("  hello!  ".require_trimmed_length_at_most("x", 5).is_err());
+    /// ```
+    fn require_trimmed_length_at_most(
         &self,
         name: &str,
-        min_length: usize,
         max_length: usize,
-    ) -> ArgumentResult<&Self> {
-        let actual_length = self.len();
-        if actual_length < min_length || actual_length > max_length {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' length must be in range [{}, {}] but was {}",
-                name, min_length, max_length, actual_length
-            )));
-        }
-        Ok(self)
-    }
+    ) -> ArgumentResult<&Self>;
 
-    fn require_match(&self, name: &str, pattern: &Regex) -> ArgumentResult<&Self> {
-        if !pattern.is_match(self) {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' must match pattern '{}'",
-                name,
-                pattern.as_str()
-            )));
-        }
-        Ok(self)
+    /// Validate that the trimmed string length is at least the minimum
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `min_length` - Minimum length of the trimmed string
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the trimmed length is greater than or equal to the
+    /// minimum, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// assert!(" abc ".require_trimmed_length_at_least("x", 3).is_ok());
+    /// assert!("  ab  ".require_trimmed_length_at_least("x", 3).is_err());
+    /// ```
+    fn require_trimmed_length_at_least(
+        &self,
+        name: &str,
+        min_length: usize,
+    ) -> ArgumentResult<&Self>;
+
+    /// Validate that string looks like an absolute URL
+    ///
+    /// This is a structural heuristic, not an RFC 3986 compliant parser. It checks
+    /// that the string:
+    ///
+    /// - starts with a scheme followed by `://`, where the scheme starts with an
+    ///   ASCII letter followed by ASCII letters, digits, `+`, `-` or `.`
+    /// - has a non-empty authority, i.e. the part before the first `/`, `?` or `#`
+    ///   following the scheme
+    /// - contains no whitespace characters
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the string looks like a URL, otherwise returns an error
+    /// describing the first failed check
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// assert!("https://example.com/x".require_url_like("endpoint").is_ok());
+    /// assert!("not a url".require_url_like("endpoint").is_err());
+    /// ```
+    fn require_url_like(&self, name: &str) -> ArgumentResult<&Self>;
+
+    /// Parse the string as a number and validate that it is within closed interval
+    ///
+    /// Fuses parsing and range checking, and reports a parse failure and an
+    /// out-of-range value as distinct errors. Values that cannot be compared with
+    /// the bounds (e.g. a parsed NaN) are rejected as out of range.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `min` - Minimum value (inclusive)
+    /// * `max` - Maximum value (inclusive)
+    ///
+    /// # Returns
+    ///
+    /// Returns the parsed value if the string parses as `T` and the value is within
+    /// [min, max] range, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// assert_eq!("50".require_parsed_in_range::<i32>("pct", 0, 100).unwrap(), 50);
+    /// assert!("150".require_parsed_in_range::<i32>("pct", 0, 100).is_err());
+    /// assert!("fifty".require_parsed_in_range::<i32>("pct", 0, 100).is_err());
+    /// ```
+    fn require_parsed_in_range<T>(&self, name: &str, min: T, max: T) -> ArgumentResult<T>
+    where
+        T: FromStr + PartialOrd + Display,
+        T::Err: Display;
+
+    /// Validate that all cased characters have the same case
+    ///
+    /// Useful for environment variable names or constants that must be either all
+    /// uppercase or all lowercase. Characters without case, such as digits, `_` or
+    /// CJK characters, are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the string does not mix uppercase and lowercase
+    /// characters, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// assert!("PATH".require_consistent_case("env").is_ok());
+    /// assert!("path".require_consistent_case("env").is_ok());
+    /// assert!("Path".require_consistent_case("env").is_err());
+    /// ```
+    fn require_consistent_case(&self, name: &str) -> ArgumentResult<&Self>;
+
+    /// Validate that a pair of delimiters is balanced
+    ///
+    /// Every `open` delimiter must be matched by a later `close` delimiter, with
+    /// proper nesting. Useful for expressions and templates. If `open` and `close`
+    /// are the same character, such as a quote, its occurrences are paired in order,
+    /// so they must appear an even number of times.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `open` - Opening delimiter
+    /// * `close` - Closing delimiter
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the delimiters are balanced, otherwise returns an error
+    /// reporting the byte position of the first unmatched closing delimiter, or of
+    /// the first opening delimiter that is never closed
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// assert!("f(g(x))".require_balanced("expr", '(', ')').is_ok());
+    /// assert!("f(x))".require_balanced("expr", '(', ')').is_err());
+    /// assert!("f((x)".require_balanced("expr", '(', ')').is_err());
+    /// ```
+    fn require_balanced(&self, name: &str, open: char, close: char) -> ArgumentResult<&Self>;
+
+    /// Validate that the string does not contain line breaks
+    ///
+    /// Useful for single-line fields such as names and titles, where line breaks
+    /// would break the layout or allow log injection. Both `\n` and `\r` are
+    /// rejected.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the string contains no line break, otherwise returns an
+    /// error reporting the byte position of the first line break
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// assert!("title".require_single_line("t").is_ok());
+    /// assert!("a\nb".require_single_line("t").is_err());
+    /// ```
+    fn require_single_line(&self, name: &str) -> ArgumentResult<&Self>;
+
+    /// Validate that the string is an email address
+    ///
+    /// The string is matched against `prism3_core::patterns::EMAIL`, see its
+    /// documentation for the limitations of the pattern.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the string is an email address, otherwise returns an
+    /// error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// assert!("a@b.com".require_email("e").is_ok());
+    /// assert!("a@b".require_email("e").is_err());
+    /// ```
+    fn require_email(&self, name: &str) -> ArgumentResult<&Self>;
+
+    /// Validate that the string ends with a newline
+    ///
+    /// POSIX defines a line as terminated by a newline, so text files are
+    /// expected to end with `'\n'`. A string ending with `"\r\n"` also passes.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the string ends with `'\n'`, otherwise returns an
+    /// error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// assert!("line\n".require_trailing_newline("content").is_ok());
+    /// assert!("line".require_trailing_newline("content").is_err());
+    /// ```
+    fn require_trailing_newline(&self, name: &str) -> ArgumentResult<&Self>;
+
+    /// Validate that the string does not end with a newline
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the string does not end with `'\n'`, otherwise
+    /// returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// assert!("line".require_no_trailing_newline("content").is_ok());
+    /// assert!("line\n".require_no_trailing_newline("content").is_err());
+    /// ```
+    fn require_no_trailing_newline(&self, name: &str) -> ArgumentResult<&Self>;
+
+    /// Validate that the string is an ASCII hexadecimal encoding
+    ///
+    /// Every character must be one of `0-9`, `a-f` or `A-F`, and the length must
+    /// be even so that the digits form whole bytes. An empty string passes.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the string is a valid hexadecimal encoding, otherwise
+    /// returns an error reporting either the first invalid character or the odd
+    /// length
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// assert!("DeadBeef".require_ascii_hex("key").is_ok());
+    /// assert!("deadbeez".require_ascii_hex("key").is_err());
+    /// assert!("abc".require_ascii_hex("key").is_err());
+    /// ```
+    fn require_ascii_hex(&self, name: &str) -> ArgumentResult<&Self>;
+
+    /// Validate that the string is an ASCII hexadecimal encoding and decode it
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    ///
+    /// # Returns
+    ///
+    /// Returns the decoded bytes if the string passes `require_ascii_hex`,
+    /// otherwise returns its error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// assert_eq!("deadbeef".decode_hex("key").unwrap(), vec![0xde, 0xad, 0xbe, 0xef]);
+    /// assert!("xyz0".decode_hex("key").is_err());
+    /// ```
+    fn decode_hex(&self, name: &str) -> ArgumentResult<Vec<u8>>;
+
+    /// Validate that the string starts with a prefix and return the remainder
+    ///
+    /// Useful to validate and parse in one step, e.g. extracting the token of a
+    /// `"Bearer <token>"` authorization header.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `prefix` - Required prefix
+    ///
+    /// # Returns
+    ///
+    /// Returns the part of the string after the prefix if the string starts with
+    /// it, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringArgument;
+    ///
+    /// assert_eq!("Bearer abc".require_strip_prefix("auth", "Bearer ").unwrap(), "abc");
+    /// assert!("Basic abc".require_strip_prefix("auth", "Bearer ").is_err());
+    /// ```
+    fn require_strip_prefix(&self, name: &str, prefix: &str) -> ArgumentResult<&str>;
+}
+
+impl StringArgument for str {
+    fn require_non_blank(&self, name: &str) -> ArgumentResult<&Self> {
+        if self.trim().is_empty() {
+            return Err(ArgumentError::for_param(
+                name,
+                "cannot be empty or contain only whitespace characters",
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_non_empty(&self, name: &str) -> ArgumentResult<&Self> {
+        if self.is_empty() {
+            return Err(ArgumentError::for_param(name, "cannot be empty"));
+        }
+        Ok(self)
+    }
+
+    fn require_length_be(&self, name: &str, length: usize) -> ArgumentResult<&Self> {
+        let actual_length = self.len();
+        if actual_length != length {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("length must be {} but was {}", length, actual_length),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_length_at_least(&self, name: &str, min_length: usize) -> ArgumentResult<&Self> {
+        let actual_length = self.len();
+        if actual_length < min_length {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "length must be at least {} but was {}",
+                    min_length, actual_length
+                ),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_length_at_most(&self, name: &str, max_length: usize) -> ArgumentResult<&Self> {
+        let actual_length = self.len();
+        if actual_length > max_length {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "length must be at most {} but was {}",
+                    max_length, actual_length
+                ),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_length_in_range(
+        &self,
+        name: &str,
+        min_length: usize,
+        max_length: usize,
+    ) -> ArgumentResult<&Self> {
+        let actual_length = self.len();
+        if actual_length < min_length || actual_length > max_length {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "length must be in range [{}, {}] but was {}",
+                    min_length, max_length, actual_length
+                ),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_match(&self, name: &str, pattern: &Regex) -> ArgumentResult<&Self> {
+        if !pattern.is_match(self) {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must match pattern '{}'", pattern.as_str()),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_not_match(&self, name: &str, pattern: &Regex) -> ArgumentResult<&Self> {
+        if pattern.is_match(self) {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("cannot match pattern '{}'", pattern.as_str()),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_hex_string(&self, name: &str, expected_len: usize) -> ArgumentResult<&Self> {
+        if self.len() != expected_len || !self.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "must be a hexadecimal string of length {} but was: '{}'",
+                    expected_len, self
+                ),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_uuid_format(&self, name: &str) -> ArgumentResult<&Self> {
+        let bytes = self.as_bytes();
+        let valid = bytes.len() == 36
+            && bytes.iter().enumerate().all(|(i, b)| match i {
+                8 | 13 | 18 | 23 => *b == b'-',
+                _ => b.is_ascii_hexdigit(),
+            });
+        if !valid {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("is not a valid UUID: '{}'", self),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_ends_with_one_of(&self, name: &str, suffixes: &[&str]) -> ArgumentResult<&Self> {
+        if !suffixes.iter().any(|suffix| self.ends_with(suffix)) {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must end with one of {:?} but was '{}'", suffixes, self),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_ends_with_one_of_ignore_case(
+        &self,
+        name: &str,
+        suffixes: &[&str],
+    ) -> ArgumentResult<&Self> {
+        let lower = self.to_lowercase();
+        if !suffixes
+            .iter()
+            .any(|suffix| lower.ends_with(&suffix.to_lowercase()))
+        {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "must end with one of {:?} (ignoring case) but was '{}'",
+                    suffixes, self
+                ),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_no_control_chars(&self, name: &str) -> ArgumentResult<&Self> {
+        if let Some((index, c)) = self.char_indices().find(|(_, c)| c.is_control()) {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "contains control character U+{:04X} at byte {}",
+                    c as u32, index
+                ),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_ascii_length_be(&self, name: &str, length: usize) -> ArgumentResult<&Self> {
+        if !self.is_ascii() {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must be ASCII but was: '{}'", self),
+            ));
+        }
+        self.require_length_be(name, length)
+    }
+
+    fn require_no_leading_zero(&self, name: &str) -> ArgumentResult<&Self> {
+        if self.len() > 1 && self.starts_with('0') {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must not have a leading zero: '{}'", self),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_chars_in(&self, name: &str, allowed: &str) -> ArgumentResult<&Self> {
+        self.require_chars_matching(
+            name,
+            |c| allowed.contains(c),
+            &format!("one of {:?}", allowed),
+        )
+    }
+
+    fn require_chars_matching<F>(&self, name: &str, f: F, desc: &str) -> ArgumentResult<&Self>
+    where
+        F: Fn(char) -> bool,
+    {
+        if let Some((index, c)) = self.char_indices().find(|(_, c)| !f(*c)) {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "contains character {:?} at byte {} that is not {}",
+                    c, index, desc
+                ),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_trimmed_length_at_most(
+        &self,
+        name: &str,
+        max_length: usize,
+    ) -> ArgumentResult<&Self> {
+        let trimmed_length = self.trim().len();
+        if trimmed_length > max_length {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "trimmed length must be at most {} but was {}",
+                    max_length, trimmed_length
+                ),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_trimmed_length_at_least(
+        &self,
+        name: &str,
+        min_length: usize,
+    ) -> ArgumentResult<&Self> {
+        let trimmed_length = self.trim().len();
+        if trimmed_length < min_length {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "trimmed length must be at least {} but was {}",
+                    min_length, trimmed_length
+                ),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_url_like(&self, name: &str) -> ArgumentResult<&Self> {
+        let problem = match self.split_once("://") {
+            None => Some("missing scheme"),
+            Some((scheme, rest)) => {
+                let mut chars = scheme.chars();
+                let valid_scheme = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+                    && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+                let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+                if !valid_scheme {
+                    Some("invalid scheme")
+                } else if authority.is_empty() {
+                    Some("empty authority")
+                } else if self.chars().any(char::is_whitespace) {
+                    Some("contains whitespace")
+                } else {
+                    None
+                }
+            }
+        };
+        if let Some(problem) = problem {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("is not a valid URL ({}): '{}'", problem, self),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_parsed_in_range<T>(&self, name: &str, min: T, max: T) -> ArgumentResult<T>
+    where
+        T: FromStr + PartialOrd + Display,
+        T::Err: Display,
+    {
+        let value: T = self.parse().map_err(|e: T::Err| {
+            ArgumentError::for_param(name, &format!("could not be parsed from '{}': {}", self, e))
+        })?;
+        // NaN is incomparable with the bounds and must not pass the range check
+        if !matches!(
+            (value.partial_cmp(&min), value.partial_cmp(&max)),
+            (
+                Some(Ordering::Greater | Ordering::Equal),
+                Some(Ordering::Less | Ordering::Equal)
+            )
+        ) {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must be in range [{}, {}] but was: {}", min, max, value),
+            ));
+        }
+        Ok(value)
+    }
+
+    fn require_consistent_case(&self, name: &str) -> ArgumentResult<&Self> {
+        if self.chars().any(char::is_uppercase) && self.chars().any(char::is_lowercase) {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must be all uppercase or all lowercase: '{}'", self),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_balanced(&self, name: &str, open: char, close: char) -> ArgumentResult<&Self> {
+        // Byte positions of the currently unclosed opening delimiters
+        let mut unclosed = Vec::new();
+        for (index, c) in self.char_indices() {
+            // Try closing first, so that identical delimiters alternate between
+            // opening and closing
+            if c == close && !unclosed.is_empty() {
+                unclosed.pop();
+            } else if c == open {
+                unclosed.push(index);
+            } else if c == close {
+                return Err(ArgumentError::for_param(
+                    name,
+                    &format!("has unmatched '{}' at byte {}: '{}'", close, index, self),
+                ));
+            }
+        }
+        if let Some(index) = unclosed.first() {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("has unclosed '{}' at byte {}: '{}'", open, index, self),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_single_line(&self, name: &str) -> ArgumentResult<&Self> {
+        if let Some(index) = self.find(['\n', '\r']) {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "must be a single line but contains a line break at byte {}: {:?}",
+                    index, self
+                ),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_email(&self, name: &str) -> ArgumentResult<&Self> {
+        if !patterns::email().is_match(self) {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("is not a valid email address: '{}'", self),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_trailing_newline(&self, name: &str) -> ArgumentResult<&Self> {
+        if !self.ends_with('\n') {
+            return Err(ArgumentError::for_param(
+                name,
+                "must end with a newline but does not",
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_no_trailing_newline(&self, name: &str) -> ArgumentResult<&Self> {
+        if self.ends_with('\n') {
+            return Err(ArgumentError::for_param(
+                name,
+                "must not end with a newline but does",
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_ascii_hex(&self, name: &str) -> ArgumentResult<&Self> {
+        if let Some((index, c)) = self.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "contains non-hexadecimal character '{}' at byte {}: '{}'",
+                    c, index, self
+                ),
+            ));
+        }
+        if self.len() % 2 != 0 {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "must have an even number of hexadecimal digits but has {}: '{}'",
+                    self.len(),
+                    self
+                ),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn decode_hex(&self, name: &str) -> ArgumentResult<Vec<u8>> {
+        self.require_ascii_hex(name)?;
+        // All characters are ASCII hexadecimal digits, so parsing cannot fail
+        Ok(self
+            .as_bytes()
+            .chunks(2)
+            .map(|digits| {
+                let high = (digits[0] as char).to_digit(16).unwrap_or(0);
+                let low = (digits[1] as char).to_digit(16).unwrap_or(0);
+                (high * 16 + low) as u8
+            })
+            .collect())
+    }
+
+    fn require_strip_prefix(&self, name: &str, prefix: &str) -> ArgumentResult<&str> {
+        self.strip_prefix(prefix).ok_or_else(|| {
+            ArgumentError::for_param(
+                name,
+                &format!("must start with '{}' but was: '{}'", prefix, self),
+            )
+        })
+    }
+}
+
+impl StringArgument for String {
+    fn require_non_blank(&self, name: &str) -> ArgumentResult<&Self> {
+        if self.trim().is_empty() {
+            return Err(ArgumentError::for_param(
+                name,
+                "cannot be empty or contain only whitespace characters",
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_non_empty(&self, name: &str) -> ArgumentResult<&Self> {
+        self.as_str().require_non_empty(name)?;
+        Ok(self)
+    }
+
+    fn require_length_be(&self, name: &str, length: usize) -> ArgumentResult<&Self> {
+        let actual_length = self.len();
+        if actual_length != length {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("length must be {} but was {}", length, actual_length),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_length_at_least(&self, name: &str, min_length: usize) -> ArgumentResult<&Self> {
+        let actual_length = self.len();
+        if actual_length < min_length {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "length must be at least {} but was {}",
+                    min_length, actual_length
+                ),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_length_at_most(&self, name: &str, max_length: usize) -> ArgumentResult<&Self> {
+        let actual_length = self.len();
+        if actual_length > max_length {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "length must be at most {} but was {}",
+                    max_length, actual_length
+                ),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_length_in_range(
+        &self,
+        name: &str,
+        min_length: usize,
+        max_length: usize,
+    ) -> ArgumentResult<&Self> {
+        let actual_length = self.len();
+        if actual_length < min_length || actual_length > max_length {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "length must be in range [{}, {}] but was {}",
+                    min_length, max_length, actual_length
+                ),
+            ));
+        }
+        Ok(self)
+    }
+
+    fn require_match(&self, name: &str, pattern: &Regex) -> ArgumentResult<&Self> {
+        if !pattern.is_match(self) {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("must match pattern '{}'", pattern.as_str()),
+            ));
+        }
+        Ok(self)
     }
 
     fn require_not_match(&self, name: &str, pattern: &Regex) -> ArgumentResult<&Self> {
         if pattern.is_match(self) {
-            return Err(ArgumentError::new(format!(
-                "Parameter '{}' cannot match pattern '{}'",
+            return Err(ArgumentError::for_param(
                 name,
-                pattern.as_str()
-            )));
+                &format!("cannot match pattern '{}'", pattern.as_str()),
+            ));
         }
         Ok(self)
     }
+
+    fn require_hex_string(&self, name: &str, expected_len: usize) -> ArgumentResult<&Self> {
+        self.as_str().require_hex_string(name, expected_len)?;
+        Ok(self)
+    }
+
+    fn require_uuid_format(&self, name: &str) -> ArgumentResult<&Self> {
+        self.as_str().require_uuid_format(name)?;
+        Ok(self)
+    }
+
+    fn require_ends_with_one_of(&self, name: &str, suffixes: &[&str]) -> ArgumentResult<&Self> {
+        self.as_str().require_ends_with_one_of(name, suffixes)?;
+        Ok(self)
+    }
+
+    fn require_ends_with_one_of_ignore_case(
+        &self,
+        name: &str,
+        suffixes: &[&str],
+    ) -> ArgumentResult<&Self> {
+        self.as_str()
+            .require_ends_with_one_of_ignore_case(name, suffixes)?;
+        Ok(self)
+    }
+
+    fn require_no_control_chars(&self, name: &str) -> ArgumentResult<&Self> {
+        self.as_str().require_no_control_chars(name)?;
+        Ok(self)
+    }
+
+    fn require_ascii_length_be(&self, name: &str, length: usize) -> ArgumentResult<&Self> {
+        self.as_str().require_ascii_length_be(name, length)?;
+        Ok(self)
+    }
+
+    fn require_no_leading_zero(&self, name: &str) -> ArgumentResult<&Self> {
+        self.as_str().require_no_leading_zero(name)?;
+        Ok(self)
+    }
+
+    fn require_chars_in(&self, name: &str, allowed: &str) -> ArgumentResult<&Self> {
+        self.as_str().require_chars_in(name, allowed)?;
+        Ok(self)
+    }
+
+    fn require_chars_matching<F>(&self, name: &str, f: F, desc: &str) -> ArgumentResult<&Self>
+    where
+        F: Fn(char) -> bool,
+    {
+        self.as_str().require_chars_matching(name, f, desc)?;
+        Ok(self)
+    }
+
+    fn require_trimmed_length_at_most(
+        &self,
+        name: &str,
+        max_length: usize,
+    ) -> ArgumentResult<&Self> {
+        self.as_str()
+            .require_trimmed_length_at_most(name, max_length)?;
+        Ok(self)
+    }
+
+    fn require_trimmed_length_at_least(
+        &self,
+        name: &str,
+        min_length: usize,
+    ) -> ArgumentResult<&Self> {
+        self.as_str()
+            .require_trimmed_length_at_least(name, min_length)?;
+        Ok(self)
+    }
+
+    fn require_url_like(&self, name: &str) -> ArgumentResult<&Self> {
+        self.as_str().require_url_like(name)?;
+        Ok(self)
+    }
+
+    fn require_parsed_in_range<T>(&self, name: &str, min: T, max: T) -> ArgumentResult<T>
+    where
+        T: FromStr + PartialOrd + Display,
+        T::Err: Display,
+    {
+        self.as_str().require_parsed_in_range(name, min, max)
+    }
+
+    fn require_consistent_case(&self, name: &str) -> ArgumentResult<&Self> {
+        self.as_str().require_consistent_case(name)?;
+        Ok(self)
+    }
+
+    fn require_balanced(&self, name: &str, open: char, close: char) -> ArgumentResult<&Self> {
+        self.as_str().require_balanced(name, open, close)?;
+        Ok(self)
+    }
+
+    fn require_single_line(&self, name: &str) -> ArgumentResult<&Self> {
+        self.as_str().require_single_line(name)?;
+        Ok(self)
+    }
+
+    fn require_email(&self, name: &str) -> ArgumentResult<&Self> {
+        self.as_str().require_email(name)?;
+        Ok(self)
+    }
+
+    fn require_trailing_newline(&self, name: &str) -> ArgumentResult<&Self> {
+        self.as_str().require_trailing_newline(name)?;
+        Ok(self)
+    }
+
+    fn require_no_trailing_newline(&self, name: &str) -> ArgumentResult<&Self> {
+        self.as_str().require_no_trailing_newline(name)?;
+        Ok(self)
+    }
+
+    fn require_ascii_hex(&self, name: &str) -> ArgumentResult<&Self> {
+        self.as_str().require_ascii_hex(name)?;
+        Ok(self)
+    }
+
+    fn decode_hex(&self, name: &str) -> ArgumentResult<Vec<u8>> {
+        self.as_str().decode_hex(name)
+    }
+
+    fn require_strip_prefix(&self, name: &str, prefix: &str) -> ArgumentResult<&str> {
+        self.as_str().require_strip_prefix(name, prefix)
+    }
+}
+
+/// Validate that a string names one of the allowed enum variants and parse it
+///
+/// Membership in `variants` is checked first, so that the error message lists the
+/// allowed options. Afterwards the value is parsed with the `FromStr`
+/// implementation of the target type.
+///
+/// # Parameters
+///
+/// * `name` - Parameter name
+/// * `value` - String to validate and parse
+/// * `variants` - Allowed variant names
+///
+/// # Returns
+///
+/// Returns the parsed value if it is an allowed variant that parses successfully,
+/// otherwise returns an error
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::require_enum;
+/// use std::str::FromStr;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Level { Low, High }
+///
+/// impl FromStr for Level {
+///     type Err = String;
+///
+///     fn from_str(s: &str) -> Result<Self, Self::Err> {
+///         match s {
+///             "low" => Ok(Level::Low),
+///             "high" => Ok(Level::High),
+///             _ => Err(format!("unknown level: {}", s)),
+///         }
+///     }
+/// }
+///
+/// let level: Level = require_enum("level", "high", &["low", "high"]).unwrap();
+/// assert_eq!(level, Level::High);
+/// assert!(require_enum::<Level>("level", "medium", &["low", "high"]).is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub fn require_enum<T>(name: &str, value: &str, variants: &[&str]) -> ArgumentResult<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    if !variants.contains(&value) {
+        return Err(ArgumentError::for_param(
+            name,
+            &format!("must be one of {:?} but was '{}'", variants, value),
+        ));
+    }
+    value.parse().map_err(|e: T::Err| {
+        ArgumentError::for_param(
+            name,
+            &format!("could not be parsed from '{}': {}", value, e),
+        )
+    })
+}
+
+/// A string validated to be non-blank
+///
+/// A `NonBlankString` can only be constructed through [`NonBlankString::new`],
+/// which rejects empty and whitespace-only strings. Passing a `NonBlankString`
+/// between layers carries the invariant in the type, so downstream code does not
+/// need to validate the string again.
+///
+/// The string content is accessible through `Deref<Target = str>`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::NonBlankString;
+///
+/// let username = NonBlankString::new("alice").unwrap();
+/// assert_eq!(username.len(), 5);
+/// assert_eq!(username.to_string(), "alice");
+///
+/// assert!(NonBlankString::new("   ").is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NonBlankString(String);
+
+impl NonBlankString {
+    /// Create a non-blank string, rejecting empty and whitespace-only strings
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - String to wrap
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped string if it is not blank, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::NonBlankString;
+    ///
+    /// assert!(NonBlankString::new("hello").is_ok());
+    /// assert!(NonBlankString::new("").is_err());
+    /// ```
+    pub fn new(value: impl Into<String>) -> ArgumentResult<Self> {
+        let value = value.into();
+        if value.trim().is_empty() {
+            return Err(ArgumentError::new(
+                "String cannot be empty or contain only whitespace characters",
+            ));
+        }
+        Ok(NonBlankString(value))
+    }
+
+    /// Get the string content
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped string as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Consume the wrapper and return the wrapped string
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped `String`
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for NonBlankString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for NonBlankString {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for NonBlankString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
 }
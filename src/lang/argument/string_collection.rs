@@ -0,0 +1,173 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # String Collection Argument Validation
+//!
+//! Provides validation functionality for collections of strings.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use super::error::{
+    ArgumentError,
+    ArgumentResult,
+};
+
+/// String collection argument validation trait
+///
+/// Provides validation methods for the string elements of a collection, either
+/// individually or in relation to each other. Implemented for slices and `Vec`s of any element type implementing
+/// `AsRef<str>`, such as `String` and `&str`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::{StringCollectionArgument, ArgumentResult};
+///
+/// fn register_commands(commands: &[&str]) -> ArgumentResult<()> {
+///     commands.require_prefix_free("commands")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+pub trait StringCollectionArgument {
+    /// Validate that no element is a prefix of another element
+    ///
+    /// Equal elements are considered prefixes of each other. Every pair of elements
+    /// is compared, so the complexity is O(n²) in the number of elements, which is
+    /// fine for small sets such as routing tables or command names.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Collection name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the collection is prefix-free, otherwise returns an error
+    /// naming the indices of the first conflicting elements
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringCollectionArgument;
+    ///
+    /// assert!(["get", "set", "delete"].require_prefix_free("commands").is_ok());
+    /// assert!(["get", "getall"].require_prefix_free("commands").is_err());
+    /// ```
+    fn require_prefix_free(&self, name: &str) -> ArgumentResult<&Self>;
+
+    /// Validate that no element has leading or trailing whitespace
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Collection name
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if every element is trimmed, otherwise returns an error
+    /// naming the index of the first element with surrounding whitespace
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringCollectionArgument;
+    ///
+    /// assert!(["rust", "web"].require_all_trimmed("tags").is_ok());
+    /// assert!(["rust", " web"].require_all_trimmed("tags").is_err());
+    /// ```
+    fn require_all_trimmed(&self, name: &str) -> ArgumentResult<&Self>;
+
+    /// Returns the elements with leading and trailing whitespace removed
+    ///
+    /// This helper does not validate anything. It is meant to normalize input that
+    /// is allowed to be padded, as an alternative to rejecting it with
+    /// `require_all_trimmed`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the trimmed elements, in order
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::argument::StringCollectionArgument;
+    ///
+    /// assert_eq!([" rust", "web "].trim_all(), vec!["rust", "web"]);
+    /// ```
+    fn trim_all(&self) -> Vec<String>;
+}
+
+impl<S> StringCollectionArgument for [S]
+where
+    S: AsRef<str>,
+{
+    fn require_prefix_free(&self, name: &str) -> ArgumentResult<&Self> {
+        for (i, a) in self.iter().enumerate() {
+            for (j, b) in self.iter().enumerate().skip(i + 1) {
+                let (a, b) = (a.as_ref(), b.as_ref());
+                let conflict = if a.len() <= b.len() {
+                    b.starts_with(a).then_some((i, a, j, b))
+                } else {
+                    a.starts_with(b).then_some((j, b, i, a))
+                };
+                if let Some((prefix_index, prefix, index, value)) = conflict {
+                    return Err(ArgumentError::new(format!(
+                        "Collection '{}': element at index {} ('{}') is a prefix of element at index {} ('{}')",
+                        name, prefix_index, prefix, index, value
+                    )));
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    fn require_all_trimmed(&self, name: &str) -> ArgumentResult<&Self> {
+        if let Some((index, value)) = self
+            .iter()
+            .map(AsRef::as_ref)
+            .enumerate()
+            .find(|(_, value)| value.trim() != *value)
+        {
+            return Err(ArgumentError::new(format!(
+                "Collection '{}': element at index {} has leading or trailing whitespace: '{}'",
+                name, index, value
+            )));
+        }
+        Ok(self)
+    }
+
+    fn trim_all(&self) -> Vec<String> {
+        self.iter()
+            .map(|value| value.as_ref().trim().to_string())
+            .collect()
+    }
+}
+
+impl<S> StringCollectionArgument for Vec<S>
+where
+    S: AsRef<str>,
+{
+    fn require_prefix_free(&self, name: &str) -> ArgumentResult<&Self> {
+        self.as_slice().require_prefix_free(name)?;
+        Ok(self)
+    }
+
+    fn require_all_trimmed(&self, name: &str) -> ArgumentResult<&Self> {
+        self.as_slice().require_all_trimmed(name)?;
+        Ok(self)
+    }
+
+    fn trim_all(&self) -> Vec<String> {
+        self.as_slice().trim_all()
+    }
+}
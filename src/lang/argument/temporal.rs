@@ -0,0 +1,81 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Temporal Argument Validation
+//!
+//! Provides validation functionality for date and time type arguments.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use super::error::{
+    ArgumentError,
+    ArgumentResult,
+};
+use chrono::NaiveDate;
+
+/// Validate that a date lies within an inclusive range
+///
+/// Dates are formatted in ISO-8601 (`YYYY-MM-DD`) in error messages. An invalid
+/// range, i.e. `min` after `max`, is reported with a distinct error regardless of
+/// the value being validated.
+///
+/// # Parameters
+///
+/// * `name` - Parameter name
+/// * `value` - Date to validate
+/// * `min` - Earliest allowed date (inclusive)
+/// * `max` - Latest allowed date (inclusive)
+///
+/// # Returns
+///
+/// Returns `Ok(value)` if `min <= value <= max`, otherwise returns an error
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use chrono::NaiveDate;
+/// use prism3_core::lang::argument::require_date_in_range;
+///
+/// let min = NaiveDate::from_ymd_opt(1900, 1, 1).unwrap();
+/// let max = NaiveDate::from_ymd_opt(2025, 12, 31).unwrap();
+/// let birthday = NaiveDate::from_ymd_opt(1990, 6, 15).unwrap();
+/// assert_eq!(require_date_in_range("birthday", birthday, min, max).unwrap(), birthday);
+/// assert!(require_date_in_range("birthday", max.succ_opt().unwrap(), min, max).is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub fn require_date_in_range(
+    name: &str,
+    value: NaiveDate,
+    min: NaiveDate,
+    max: NaiveDate,
+) -> ArgumentResult<NaiveDate> {
+    if min > max {
+        return Err(ArgumentError::for_param(
+            name,
+            &format!(
+                "has an invalid date range: minimum {} is after maximum {}",
+                min, max
+            ),
+        ));
+    }
+    if value < min || value > max {
+        return Err(ArgumentError::for_param(
+            name,
+            &format!(
+                "must be in date range [{}, {}] but was: {}",
+                min, max, value
+            ),
+        ));
+    }
+    Ok(value)
+}
@@ -0,0 +1,176 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Typed Argument Error
+//!
+//! Provides a pattern-matchable alternative to the string-based `ArgumentError`,
+//! available with the `typed-error` feature.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use super::error::ArgumentError;
+use thiserror::Error;
+
+/// Typed argument validation error
+///
+/// Each variant corresponds to one kind of validation failure and carries the
+/// relevant fields, so callers can match on the failure instead of inspecting
+/// error messages. Values and bounds are stored in their displayed form, and the
+/// `Display` output of each variant is identical to the message produced by the
+/// corresponding validation method.
+///
+/// Converting a `TypedArgumentError` into an `ArgumentError` keeps the message and,
+/// for the variants about a single parameter, the parameter name, exactly as the
+/// corresponding validation method would. Since an `ArgumentError` only carries a
+/// message and an optional parameter name, converting it back yields the `Other`
+/// variant with these two fields.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::argument::{ArgumentError, TypedArgumentError};
+///
+/// let error = TypedArgumentError::OutOfRange {
+///     parameter: "age".to_string(),
+///     min: "0".to_string(),
+///     max: "150".to_string(),
+///     actual: "200".to_string(),
+/// };
+/// let error: ArgumentError = error.into();
+/// assert_eq!(error.message(), "Parameter 'age' must be in range [0, 150] but was: 200");
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TypedArgumentError {
+    /// A string parameter is empty or contains only whitespace characters
+    #[error("Parameter '{parameter}' cannot be empty or contain only whitespace characters")]
+    Blank {
+        /// Parameter name
+        parameter: String,
+    },
+
+    /// A collection parameter is empty
+    #[error("Collection '{parameter}' cannot be empty")]
+    EmptyCollection {
+        /// Collection name
+        parameter: String,
+    },
+
+    /// A numeric parameter is zero or negative
+    #[error("Parameter '{parameter}' must be positive but was: {actual}")]
+    NotPositive {
+        /// Parameter name
+        parameter: String,
+        /// Actual value
+        actual: String,
+    },
+
+    /// A numeric parameter is negative
+    #[error("Parameter '{parameter}' must be non-negative but was: {actual}")]
+    Negative {
+        /// Parameter name
+        parameter: String,
+        /// Actual value
+        actual: String,
+    },
+
+    /// A parameter is outside a closed range
+    #[error("Parameter '{parameter}' must be in range [{min}, {max}] but was: {actual}")]
+    OutOfRange {
+        /// Parameter name
+        parameter: String,
+        /// Minimum value (inclusive)
+        min: String,
+        /// Maximum value (inclusive)
+        max: String,
+        /// Actual value
+        actual: String,
+    },
+
+    /// The bounds of a range check are inverted
+    #[error(
+        "Parameter '{parameter}' has invalid bounds: minimum {min} is greater than maximum {max}"
+    )]
+    InvalidBounds {
+        /// Parameter name
+        parameter: String,
+        /// Minimum value
+        min: String,
+        /// Maximum value
+        max: String,
+    },
+
+    /// Any other validation failure, described by its message
+    #[error("{message}")]
+    Other {
+        /// Parameter name, if known
+        parameter: Option<String>,
+        /// Error message
+        message: String,
+    },
+}
+
+impl From<TypedArgumentError> for ArgumentError {
+    fn from(error: TypedArgumentError) -> Self {
+        match error {
+            TypedArgumentError::Blank { parameter } => ArgumentError::for_param(
+                &parameter,
+                "cannot be empty or contain only whitespace characters",
+            ),
+            TypedArgumentError::EmptyCollection { parameter } => {
+                ArgumentError::new(format!("Collection '{}' cannot be empty", parameter))
+            }
+            TypedArgumentError::NotPositive { parameter, actual } => ArgumentError::for_param(
+                &parameter,
+                &format!("must be positive but was: {}", actual),
+            ),
+            TypedArgumentError::Negative { parameter, actual } => ArgumentError::for_param(
+                &parameter,
+                &format!("must be non-negative but was: {}", actual),
+            ),
+            TypedArgumentError::OutOfRange {
+                parameter,
+                min,
+                max,
+                actual,
+            } => ArgumentError::for_param(
+                &parameter,
+                &format!("must be in range [{}, {}] but was: {}", min, max, actual),
+            ),
+            TypedArgumentError::InvalidBounds {
+                parameter,
+                min,
+                max,
+            } => ArgumentError::for_param(
+                &parameter,
+                &format!(
+                    "has invalid bounds: minimum {} is greater than maximum {}",
+                    min, max
+                ),
+            ),
+            TypedArgumentError::Other { parameter, message } => {
+                ArgumentError::with_parameter(message, parameter)
+            }
+        }
+    }
+}
+
+impl From<ArgumentError> for TypedArgumentError {
+    fn from(error: ArgumentError) -> Self {
+        TypedArgumentError::Other {
+            parameter: error.parameter().map(str::to_string),
+            message: error.message().to_string(),
+        }
+    }
+}
@@ -15,6 +15,7 @@
 //!
 //! Haixing Hu
 
+use super::argument::check_argument_with_message;
 use std::error::Error;
 
 /// A type alias for `Box<dyn Error + Send + Sync>`.
@@ -274,3 +275,40 @@ pub type BoxError = Box<dyn Error + Send + Sync>;
 ///
 /// Haixing Hu
 pub type BoxResult<T> = Result<T, BoxError>;
+
+/// Validates an argument condition in a `BoxResult`-returning function.
+///
+/// This is a shorthand for `check_argument_with_message` whose error is boxed, so
+/// that functions returning `BoxResult` can validate their arguments without
+/// converting the error manually.
+///
+/// # Parameters
+///
+/// * `cond` - The condition that must hold
+/// * `msg` - The error message if the condition does not hold
+///
+/// # Returns
+///
+/// Returns `Ok(())` if the condition holds, otherwise returns a boxed
+/// `ArgumentError` with the given message.
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_core::{require_arg, BoxResult};
+///
+/// fn set_threads(count: usize) -> BoxResult<usize> {
+///     require_arg(count > 0, "Thread count must be positive")?;
+///     Ok(count)
+/// }
+///
+/// assert!(set_threads(4).is_ok());
+/// assert!(set_threads(0).is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub fn require_arg(cond: bool, msg: &str) -> BoxResult<()> {
+    check_argument_with_message(cond, msg).map_err(BoxError::from)
+}
@@ -14,6 +14,10 @@
 //!
 //! Haixing Hu
 
+use super::argument::{
+    ArgumentError,
+    ArgumentResult,
+};
 use bigdecimal::BigDecimal;
 use chrono::{
     DateTime,
@@ -27,6 +31,9 @@ use serde::{
     Deserialize,
     Serialize,
 };
+use serde_json::Value;
+use std::cmp::Ordering;
+use std::str::FromStr;
 
 /// Universal data type enumeration for cross-module type representation
 ///
@@ -147,6 +154,60 @@ pub enum DataType {
 }
 
 impl DataType {
+    /// All data types, in declaration order
+    ///
+    /// Useful for building user interfaces and for exhaustive tests that must
+    /// cover every variant.
+    pub const ALL: &'static [DataType] = &[
+        DataType::Bool,
+        DataType::Char,
+        DataType::Int8,
+        DataType::Int16,
+        DataType::Int32,
+        DataType::Int64,
+        DataType::Int128,
+        DataType::UInt8,
+        DataType::UInt16,
+        DataType::UInt32,
+        DataType::UInt64,
+        DataType::UInt128,
+        DataType::Float32,
+        DataType::Float64,
+        DataType::String,
+        DataType::Date,
+        DataType::Time,
+        DataType::DateTime,
+        DataType::Instant,
+        DataType::BigInteger,
+        DataType::BigDecimal,
+    ];
+
+    /// Numeric data types, including the big number types
+    pub const NUMERIC: &'static [DataType] = &[
+        DataType::Int8,
+        DataType::Int16,
+        DataType::Int32,
+        DataType::Int64,
+        DataType::Int128,
+        DataType::UInt8,
+        DataType::UInt16,
+        DataType::UInt32,
+        DataType::UInt64,
+        DataType::UInt128,
+        DataType::Float32,
+        DataType::Float64,
+        DataType::BigInteger,
+        DataType::BigDecimal,
+    ];
+
+    /// Date and time data types
+    pub const TEMPORAL: &'static [DataType] = &[
+        DataType::Date,
+        DataType::Time,
+        DataType::DateTime,
+        DataType::Instant,
+    ];
+
     /// Get the string representation of the data type
     ///
     /// # Returns
@@ -186,6 +247,589 @@ impl DataType {
             DataType::BigDecimal => "bigdecimal",
         }
     }
+
+    /// Get the human-readable name of the data type
+    ///
+    /// Unlike `as_str`, which returns the machine name of the data type
+    /// (e.g. `"int32"`), this returns a label suitable for user interfaces,
+    /// reports and generated documentation (e.g. `"32-bit integer"`).
+    ///
+    /// # Returns
+    ///
+    /// Returns the human-readable name of the data type
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::DataType;
+    ///
+    /// assert_eq!(DataType::Int32.display_name(), "32-bit integer");
+    /// assert_eq!(DataType::String.display_name(), "UTF-8 string");
+    /// assert_eq!(DataType::Date.display_name(), "date");
+    /// ```
+    pub const fn display_name(&self) -> &'static str {
+        match self {
+            DataType::Bool => "boolean",
+            DataType::Char => "character",
+            DataType::Int8 => "8-bit integer",
+            DataType::Int16 => "16-bit integer",
+            DataType::Int32 => "32-bit integer",
+            DataType::Int64 => "64-bit integer",
+            DataType::Int128 => "128-bit integer",
+            DataType::UInt8 => "8-bit unsigned integer",
+            DataType::UInt16 => "16-bit unsigned integer",
+            DataType::UInt32 => "32-bit unsigned integer",
+            DataType::UInt64 => "64-bit unsigned integer",
+            DataType::UInt128 => "128-bit unsigned integer",
+            DataType::Float32 => "32-bit floating-point number",
+            DataType::Float64 => "64-bit floating-point number",
+            DataType::String => "UTF-8 string",
+            DataType::Date => "date",
+            DataType::Time => "time",
+            DataType::DateTime => "date and time",
+            DataType::Instant => "UTC instant",
+            DataType::BigInteger => "arbitrary-precision integer",
+            DataType::BigDecimal => "arbitrary-precision decimal",
+        }
+    }
+
+    /// Get the JSON Schema type and format of the data type
+    ///
+    /// The returned pair can be used directly as the `type` and `format`
+    /// keywords of a JSON Schema (or OpenAPI) property definition. Types that
+    /// cannot be represented losslessly by a JSON number, such as big numbers,
+    /// are mapped to strings without a format.
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of the JSON Schema `type` and the optional `format`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::DataType;
+    ///
+    /// assert_eq!(DataType::Int32.json_schema_type(), ("integer", Some("int32")));
+    /// assert_eq!(DataType::String.json_schema_type(), ("string", None));
+    /// assert_eq!(DataType::DateTime.json_schema_type(), ("string", Some("date-time")));
+    /// ```
+    pub const fn json_schema_type(&self) -> (&'static str, Option<&'static str>) {
+        match self {
+            DataType::Bool => ("boolean", None),
+            DataType::Char => ("string", None),
+            DataType::Int8 => ("integer", Some("int8")),
+            DataType::Int16 => ("integer", Some("int16")),
+            DataType::Int32 => ("integer", Some("int32")),
+            DataType::Int64 => ("integer", Some("int64")),
+            DataType::Int128 => ("integer", Some("int128")),
+            DataType::UInt8 => ("integer", Some("uint8")),
+            DataType::UInt16 => ("integer", Some("uint16")),
+            DataType::UInt32 => ("integer", Some("uint32")),
+            DataType::UInt64 => ("integer", Some("uint64")),
+            DataType::UInt128 => ("integer", Some("uint128")),
+            DataType::Float32 => ("number", Some("float")),
+            DataType::Float64 => ("number", Some("double")),
+            DataType::String => ("string", None),
+            DataType::Date => ("string", Some("date")),
+            DataType::Time => ("string", Some("time")),
+            DataType::DateTime => ("string", Some("date-time")),
+            DataType::Instant => ("string", Some("date-time")),
+            DataType::BigInteger => ("string", None),
+            DataType::BigDecimal => ("string", None),
+        }
+    }
+
+    /// Get the canonical Rust type name of the data type
+    ///
+    /// Unlike `as_str`, which returns the machine name of the data type
+    /// (e.g. `"int32"`), this returns the Rust type path that can be emitted
+    /// directly by code generators (e.g. `"i32"`). Third-party types are fully
+    /// qualified with their crate name.
+    ///
+    /// # Returns
+    ///
+    /// Returns the Rust type name corresponding to the data type
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::DataType;
+    ///
+    /// assert_eq!(DataType::Int32.rust_type_name(), "i32");
+    /// assert_eq!(DataType::String.rust_type_name(), "String");
+    /// assert_eq!(DataType::Date.rust_type_name(), "chrono::NaiveDate");
+    /// ```
+    pub const fn rust_type_name(&self) -> &'static str {
+        match self {
+            DataType::Bool => "bool",
+            DataType::Char => "char",
+            DataType::Int8 => "i8",
+            DataType::Int16 => "i16",
+            DataType::Int32 => "i32",
+            DataType::Int64 => "i64",
+            DataType::Int128 => "i128",
+            DataType::UInt8 => "u8",
+            DataType::UInt16 => "u16",
+            DataType::UInt32 => "u32",
+            DataType::UInt64 => "u64",
+            DataType::UInt128 => "u128",
+            DataType::Float32 => "f32",
+            DataType::Float64 => "f64",
+            DataType::String => "String",
+            DataType::Date => "chrono::NaiveDate",
+            DataType::Time => "chrono::NaiveTime",
+            DataType::DateTime => "chrono::NaiveDateTime",
+            DataType::Instant => "chrono::DateTime<chrono::Utc>",
+            DataType::BigInteger => "num_bigint::BigInt",
+            DataType::BigDecimal => "bigdecimal::BigDecimal",
+        }
+    }
+
+    /// Get the fixed size in bytes of the binary representation of the data type
+    ///
+    /// The size is the size of the corresponding Rust primitive type, e.g. 4 for
+    /// `Char`. Strings, big numbers and date/time types have no fixed-size binary
+    /// representation.
+    ///
+    /// # Returns
+    ///
+    /// Returns the size in bytes for fixed-width types, or `None` for
+    /// variable-width types
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::DataType;
+    ///
+    /// assert_eq!(DataType::Int32.size_in_bytes(), Some(4));
+    /// assert_eq!(DataType::String.size_in_bytes(), None);
+    /// ```
+    pub const fn size_in_bytes(&self) -> Option<usize> {
+        match self {
+            DataType::Bool | DataType::Int8 | DataType::UInt8 => Some(1),
+            DataType::Int16 | DataType::UInt16 => Some(2),
+            DataType::Char | DataType::Int32 | DataType::UInt32 | DataType::Float32 => Some(4),
+            DataType::Int64 | DataType::UInt64 | DataType::Float64 => Some(8),
+            DataType::Int128 | DataType::UInt128 => Some(16),
+            DataType::String
+            | DataType::Date
+            | DataType::Time
+            | DataType::DateTime
+            | DataType::Instant
+            | DataType::BigInteger
+            | DataType::BigDecimal => None,
+        }
+    }
+
+    /// Get the data type corresponding to a Rust type name
+    ///
+    /// This is the inverse of `rust_type_name`. In addition to the canonical names,
+    /// the following aliases are accepted:
+    ///
+    /// - `isize` and `usize` map to `Int64` and `UInt64`, i.e. a 64-bit pointer
+    ///   width is assumed
+    /// - `str` and `&str` map to `String`
+    /// - third-party types may omit their crate path, e.g. `NaiveDate`, `BigInt`
+    ///   or `DateTime<Utc>`
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Rust type name
+    ///
+    /// # Returns
+    ///
+    /// Returns the corresponding data type, or `None` if the name is not recognized
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::DataType;
+    ///
+    /// assert_eq!(DataType::from_rust_type_name("i32"), Some(DataType::Int32));
+    /// assert_eq!(DataType::from_rust_type_name("usize"), Some(DataType::UInt64));
+    /// assert_eq!(DataType::from_rust_type_name("Vec<u8>"), None);
+    /// ```
+    pub fn from_rust_type_name(name: &str) -> Option<DataType> {
+        if let Some(data_type) = DataType::ALL
+            .iter()
+            .find(|data_type| data_type.rust_type_name() == name)
+        {
+            return Some(*data_type);
+        }
+        match name {
+            "isize" => Some(DataType::Int64),
+            "usize" => Some(DataType::UInt64),
+            "str" | "&str" => Some(DataType::String),
+            "NaiveDate" => Some(DataType::Date),
+            "NaiveTime" => Some(DataType::Time),
+            "NaiveDateTime" => Some(DataType::DateTime),
+            "DateTime<Utc>" | "chrono::DateTime<Utc>" => Some(DataType::Instant),
+            "BigInt" => Some(DataType::BigInteger),
+            "BigDecimal" => Some(DataType::BigDecimal),
+            _ => None,
+        }
+    }
+
+    /// Check whether values of this data type can be losslessly converted to the target type
+    ///
+    /// Implements the standard numeric widening lattice:
+    ///
+    /// - Every data type can be converted to itself.
+    /// - Signed integers widen to larger signed integers.
+    /// - Unsigned integers widen to larger unsigned integers and to strictly larger
+    ///   signed integers.
+    /// - Integers widen to a floating point type only if all their values fit into its
+    ///   mantissa (24 bits for `Float32`, 53 bits for `Float64`).
+    /// - `Float32` widens to `Float64`.
+    /// - All integers widen to `BigInteger` and `BigDecimal`, and `BigInteger` widens to
+    ///   `BigDecimal`.
+    ///
+    /// Floating point types never widen to big number types since they may hold `NaN`
+    /// or infinities. Non-numeric data types only convert to themselves.
+    ///
+    /// # Parameters
+    ///
+    /// * `target` - The target data type
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if every value of this type can be represented by the target type
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::DataType;
+    ///
+    /// assert!(DataType::Int8.can_widen_to(DataType::Int32));
+    /// assert!(DataType::UInt8.can_widen_to(DataType::Int16));
+    /// assert!(DataType::Float32.can_widen_to(DataType::Float64));
+    /// assert!(!DataType::Int64.can_widen_to(DataType::Float64));
+    /// assert!(!DataType::Int32.can_widen_to(DataType::Int16));
+    /// ```
+    pub fn can_widen_to(&self, target: DataType) -> bool {
+        if *self == target {
+            return true;
+        }
+        match self {
+            DataType::Int8 | DataType::Int16 => matches!(
+                target,
+                DataType::Int16
+                    | DataType::Int32
+                    | DataType::Int64
+                    | DataType::Int128
+                    | DataType::Float32
+                    | DataType::Float64
+                    | DataType::BigInteger
+                    | DataType::BigDecimal
+            ),
+            DataType::Int32 => matches!(
+                target,
+                DataType::Int64
+                    | DataType::Int128
+                    | DataType::Float64
+                    | DataType::BigInteger
+                    | DataType::BigDecimal
+            ),
+            DataType::Int64 => matches!(
+                target,
+                DataType::Int128 | DataType::BigInteger | DataType::BigDecimal
+            ),
+            DataType::UInt8 => matches!(
+                target,
+                DataType::UInt16
+                    | DataType::UInt32
+                    | DataType::UInt64
+                    | DataType::UInt128
+                    | DataType::Int16
+                    | DataType::Int32
+                    | DataType::Int64
+                    | DataType::Int128
+                    | DataType::Float32
+                    | DataType::Float64
+                    | DataType::BigInteger
+                    | DataType::BigDecimal
+            ),
+            DataType::UInt16 => matches!(
+                target,
+                DataType::UInt32
+                    | DataType::UInt64
+                    | DataType::UInt128
+                    | DataType::Int32
+                    | DataType::Int64
+                    | DataType::Int128
+                    | DataType::Float32
+                    | DataType::Float64
+                    | DataType::BigInteger
+                    | DataType::BigDecimal
+            ),
+            DataType::UInt32 => matches!(
+                target,
+                DataType::UInt64
+                    | DataType::UInt128
+                    | DataType::Int64
+                    | DataType::Int128
+                    | DataType::Float64
+                    | DataType::BigInteger
+                    | DataType::BigDecimal
+            ),
+            DataType::UInt64 => matches!(
+                target,
+                DataType::UInt128 | DataType::Int128 | DataType::BigInteger | DataType::BigDecimal
+            ),
+            DataType::Int128 | DataType::UInt128 => {
+                matches!(target, DataType::BigInteger | DataType::BigDecimal)
+            }
+            DataType::Float32 => target == DataType::Float64,
+            DataType::BigInteger => target == DataType::BigDecimal,
+            _ => false,
+        }
+    }
+
+    /// Validate that a JSON value matches this data type
+    ///
+    /// The validation follows the JSON representation used by the serde
+    /// implementations of the corresponding Rust types:
+    ///
+    /// - `Bool` requires a JSON boolean.
+    /// - `Char` requires a JSON string of exactly one character.
+    /// - Integer types require a JSON integer within the range of the type.
+    /// - Floating point types require a JSON number, within the finite range of
+    ///   `f32` for `Float32`.
+    /// - `String` requires a JSON string.
+    /// - Date and time types require a JSON string in the format produced by `chrono`,
+    ///   e.g. `"2025-01-31"`, `"12:30:00"`, `"2025-01-31T12:30:00"` and
+    ///   `"2025-01-31T12:30:00Z"`.
+    /// - `BigInteger` and `BigDecimal` accept either a JSON number or a JSON string
+    ///   holding the decimal representation, since big numbers usually exceed the
+    ///   precision of JSON numbers.
+    ///
+    /// The error does not name a parameter; use [`DataType::validate_json_named`]
+    /// to validate the value of a named parameter.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The JSON value to validate
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the JSON value matches this data type, otherwise returns an
+    /// error naming the JSON value and the expected data type
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::DataType;
+    /// use serde_json::json;
+    ///
+    /// assert!(DataType::Int32.validate_json(&json!(42)).is_ok());
+    /// assert!(DataType::Int8.validate_json(&json!(300)).is_err());
+    /// assert!(DataType::String.validate_json(&json!(true)).is_err());
+    /// ```
+    pub fn validate_json(&self, value: &Value) -> ArgumentResult<()> {
+        if !self.matches_json(value) {
+            return Err(ArgumentError::new(format!(
+                "JSON {} {} does not match data type '{}'",
+                json_kind(value),
+                value,
+                self
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validate that the JSON value of a named parameter matches this data type
+    ///
+    /// Performs the same checks as [`DataType::validate_json`], but reports a
+    /// mismatch as an error about the parameter `name`, which is recorded in the
+    /// error.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Parameter name
+    /// * `value` - The JSON value to validate
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the JSON value matches this data type, otherwise returns an
+    /// error naming the parameter, the JSON value and the expected data type
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::DataType;
+    /// use serde_json::json;
+    ///
+    /// let err = DataType::Int32.validate_json_named("port", &json!("abc")).unwrap_err();
+    /// assert_eq!(err.parameter(), Some("port"));
+    /// ```
+    pub fn validate_json_named(&self, name: &str, value: &Value) -> ArgumentResult<()> {
+        if !self.matches_json(value) {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "(JSON {} {}) does not match data type '{}'",
+                    json_kind(value),
+                    value,
+                    self
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check whether a JSON value matches this data type, see `validate_json`
+    fn matches_json(&self, value: &Value) -> bool {
+        match self {
+            DataType::Bool => value.is_boolean(),
+            DataType::Char => value.as_str().is_some_and(|s| s.chars().count() == 1),
+            DataType::Int8 => value.as_i64().is_some_and(|v| i8::try_from(v).is_ok()),
+            DataType::Int16 => value.as_i64().is_some_and(|v| i16::try_from(v).is_ok()),
+            DataType::Int32 => value.as_i64().is_some_and(|v| i32::try_from(v).is_ok()),
+            DataType::Int64 => value.is_i64(),
+            DataType::Int128 => value.is_i64() || value.is_u64(),
+            DataType::UInt8 => value.as_u64().is_some_and(|v| u8::try_from(v).is_ok()),
+            DataType::UInt16 => value.as_u64().is_some_and(|v| u16::try_from(v).is_ok()),
+            DataType::UInt32 => value.as_u64().is_some_and(|v| u32::try_from(v).is_ok()),
+            DataType::UInt64 | DataType::UInt128 => value.is_u64(),
+            DataType::Float32 => value.as_f64().is_some_and(|v| v.abs() <= f32::MAX as f64),
+            DataType::Float64 => value.is_number(),
+            DataType::String => value.is_string(),
+            DataType::Date => NaiveDate::deserialize(value).is_ok(),
+            DataType::Time => NaiveTime::deserialize(value).is_ok(),
+            DataType::DateTime => NaiveDateTime::deserialize(value).is_ok(),
+            DataType::Instant => DateTime::<Utc>::deserialize(value).is_ok(),
+            DataType::BigInteger => {
+                value.is_i64()
+                    || value.is_u64()
+                    || value.as_str().is_some_and(|s| BigInt::from_str(s).is_ok())
+            }
+            DataType::BigDecimal => {
+                value.is_number()
+                    || value
+                        .as_str()
+                        .is_some_and(|s| BigDecimal::from_str(s).is_ok())
+            }
+        }
+    }
+
+    /// Get the stable sort rank of the data type
+    ///
+    /// Data types are ranked by category: `Bool` < `Char` < signed integers <
+    /// unsigned integers < floating point numbers < `String` < date/time types <
+    /// big number types. Within a category, narrower types rank first. The rank
+    /// defines the `Ord` implementation of `DataType`, so that lists of data types
+    /// sort deterministically for display.
+    ///
+    /// # Returns
+    ///
+    /// Returns the rank of the data type, which is also its index in `DataType::ALL`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::DataType;
+    ///
+    /// assert!(DataType::Bool.rank() < DataType::Int8.rank());
+    /// assert!(DataType::String.rank() < DataType::Date.rank());
+    /// ```
+    pub const fn rank(&self) -> u8 {
+        match self {
+            DataType::Bool => 0,
+            DataType::Char => 1,
+            DataType::Int8 => 2,
+            DataType::Int16 => 3,
+            DataType::Int32 => 4,
+            DataType::Int64 => 5,
+            DataType::Int128 => 6,
+            DataType::UInt8 => 7,
+            DataType::UInt16 => 8,
+            DataType::UInt32 => 9,
+            DataType::UInt64 => 10,
+            DataType::UInt128 => 11,
+            DataType::Float32 => 12,
+            DataType::Float64 => 13,
+            DataType::String => 14,
+            DataType::Date => 15,
+            DataType::Time => 16,
+            DataType::DateTime => 17,
+            DataType::Instant => 18,
+            DataType::BigInteger => 19,
+            DataType::BigDecimal => 20,
+        }
+    }
+}
+
+impl Ord for DataType {
+    /// Compare data types by their `rank`
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+impl PartialOrd for DataType {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Validate that a binary buffer has the size of a fixed-width data type
+///
+/// Useful when reading a binary field declared with a `DataType`.
+///
+/// # Parameters
+///
+/// * `name` - Parameter name
+/// * `bytes` - Binary buffer to validate
+/// * `data_type` - Declared data type of the buffer
+///
+/// # Returns
+///
+/// Returns `Ok(())` if the length of `bytes` equals `data_type.size_in_bytes()`,
+/// otherwise returns an error. Variable-width data types are always rejected with
+/// a distinct error.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::lang::{require_byte_len_for, DataType};
+///
+/// assert!(require_byte_len_for("value", &[0, 0, 0, 1], DataType::Int32).is_ok());
+/// assert!(require_byte_len_for("value", &[0, 0, 1], DataType::Int32).is_err());
+/// assert!(require_byte_len_for("value", b"abc", DataType::String).is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub fn require_byte_len_for(name: &str, bytes: &[u8], data_type: DataType) -> ArgumentResult<()> {
+    match data_type.size_in_bytes() {
+        None => Err(ArgumentError::for_param(
+            name,
+            &format!("has type '{}' with no fixed size in bytes", data_type),
+        )),
+        Some(size) if size != bytes.len() => Err(ArgumentError::for_param(
+            name,
+            &format!(
+                "has type '{}' which expects {} bytes but got {}",
+                data_type,
+                size,
+                bytes.len()
+            ),
+        )),
+        Some(_) => Ok(()),
+    }
+}
+
+/// Get the name of the kind of a JSON value for error messages
+pub(crate) fn json_kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
 }
 
 impl std::fmt::Display for DataType {
@@ -194,6 +838,28 @@ impl std::fmt::Display for DataType {
     }
 }
 
+impl FromStr for DataType {
+    type Err = ArgumentError;
+
+    /// Parse a data type from its string representation as returned by `as_str`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use prism3_core::lang::DataType;
+    ///
+    /// assert_eq!("int32".parse::<DataType>().unwrap(), DataType::Int32);
+    /// assert!("integer".parse::<DataType>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DataType::ALL
+            .iter()
+            .find(|data_type| data_type.as_str() == s)
+            .copied()
+            .ok_or_else(|| ArgumentError::new(format!("Unknown data type: '{}'", s)))
+    }
+}
+
 // =============================================================================
 // Compile-time mapping from types to DataType
 // =============================================================================
@@ -374,3 +1040,39 @@ impl DataTypeOf for BigInt {
 impl DataTypeOf for BigDecimal {
     const DATA_TYPE: DataType = DataType::BigDecimal;
 }
+
+/// Implement `DataTypeOf` for user-defined types with a fixed `DataType`
+///
+/// Useful for newtype wrappers and enums that are stored as one of the basic
+/// data types, e.g. an ID wrapping an `i32`. Each `Type => Variant` entry maps the
+/// type to the named `DataType` variant.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::impl_data_type_of;
+/// use prism3_core::lang::{DataType, DataTypeOf};
+///
+/// struct UserId(i32);
+/// struct Email(String);
+///
+/// impl_data_type_of!(UserId => Int32, Email => String);
+///
+/// assert_eq!(UserId::DATA_TYPE, DataType::Int32);
+/// assert_eq!(Email::DATA_TYPE, DataType::String);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+///
+#[macro_export]
+macro_rules! impl_data_type_of {
+    ($($ty:ty => $variant:ident),+ $(,)?) => {
+        $(
+            impl $crate::lang::DataTypeOf for $ty {
+                const DATA_TYPE: $crate::lang::DataType = $crate::lang::DataType::$variant;
+            }
+        )+
+    };
+}
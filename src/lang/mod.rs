@@ -17,15 +17,19 @@
 pub mod argument;
 pub mod box_error;
 pub mod data_type;
+pub mod typed_value;
 
 pub use box_error::{
+    require_arg,
     BoxError,
     BoxResult,
 };
 pub use data_type::{
+    require_byte_len_for,
     DataType,
     DataTypeOf,
 };
+pub use typed_value::TypedValue;
 
 // Re-export commonly used types
 pub use argument::{
@@ -33,8 +37,18 @@ pub use argument::{
     check_state,
     ArgumentError,
     ArgumentResult,
+    ArgumentResultExt,
     CollectionArgument,
+    FloatArgument,
+    FloatSliceArgument,
+    IntegerArgument,
+    IntegerSliceArgument,
     NumericArgument,
     OptionArgument,
+    PathArgument,
+    SignedIntegerArgument,
+    SortOrder,
     StringArgument,
+    StringCollectionArgument,
+    VecArgument,
 };
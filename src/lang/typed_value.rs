@@ -0,0 +1,147 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Typed Values (Language Layer)
+//!
+//! Provides the `TypedValue` enum, a runtime value tagged with its `DataType`.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use super::{
+    argument::ArgumentError,
+    data_type::DataType,
+};
+use bigdecimal::BigDecimal;
+use chrono::{
+    DateTime,
+    NaiveDate,
+    NaiveDateTime,
+    NaiveTime,
+    Utc,
+};
+use num_bigint::BigInt;
+
+macro_rules! define_typed_value {
+    ($($variant:ident($ty:ty) => $doc:literal),* $(,)?) => {
+        /// Runtime value tagged with its data type
+        ///
+        /// `TypedValue` is the runtime counterpart of the compile-time `DataTypeOf`
+        /// mapping: each variant holds a value of the Rust type that `DataTypeOf`
+        /// maps to the `DataType` variant of the same name. This makes it possible to
+        /// store values of different types in dynamic containers while keeping their
+        /// type information.
+        ///
+        /// Values are created with `TypedValue::from` and extracted with
+        /// `TryFrom`/`TryInto`, which fails with an `ArgumentError` if the value is
+        /// of another type.
+        ///
+        /// # Examples
+        ///
+        /// ```rust,ignore
+        /// use prism3_core::lang::{DataType, TypedValue};
+        ///
+        /// let value = TypedValue::from(42i32);
+        /// assert_eq!(value.data_type(), DataType::Int32);
+        ///
+        /// let number: i32 = value.clone().try_into().unwrap();
+        /// assert_eq!(number, 42);
+        ///
+        /// let result: Result<String, _> = value.try_into();
+        /// assert!(result.is_err());
+        /// ```
+        ///
+        /// # Author
+        ///
+        /// Haixing Hu
+        ///
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum TypedValue {
+            $(
+                #[doc = $doc]
+                $variant($ty),
+            )*
+        }
+
+        impl TypedValue {
+            /// Get the data type of the value
+            ///
+            /// # Returns
+            ///
+            /// Returns the `DataType` variant with the same name as the value variant
+            ///
+            /// # Examples
+            ///
+            /// ```rust,ignore
+            /// use prism3_core::lang::{DataType, TypedValue};
+            ///
+            /// assert_eq!(TypedValue::from(true).data_type(), DataType::Bool);
+            /// assert_eq!(TypedValue::from("hi").data_type(), DataType::String);
+            /// ```
+            pub fn data_type(&self) -> DataType {
+                match self {
+                    $(TypedValue::$variant(_) => DataType::$variant,)*
+                }
+            }
+        }
+
+        $(
+            impl From<$ty> for TypedValue {
+                fn from(value: $ty) -> Self {
+                    TypedValue::$variant(value)
+                }
+            }
+
+            impl TryFrom<TypedValue> for $ty {
+                type Error = ArgumentError;
+
+                fn try_from(value: TypedValue) -> Result<Self, Self::Error> {
+                    match value {
+                        TypedValue::$variant(inner) => Ok(inner),
+                        other => Err(ArgumentError::new(format!(
+                            "Cannot convert a value of type '{}' to type '{}'",
+                            other.data_type(),
+                            DataType::$variant
+                        ))),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+define_typed_value! {
+    Bool(bool) => "Boolean value",
+    Char(char) => "Character value",
+    Int8(i8) => "8-bit signed integer value",
+    Int16(i16) => "16-bit signed integer value",
+    Int32(i32) => "32-bit signed integer value",
+    Int64(i64) => "64-bit signed integer value",
+    Int128(i128) => "128-bit signed integer value",
+    UInt8(u8) => "8-bit unsigned integer value",
+    UInt16(u16) => "16-bit unsigned integer value",
+    UInt32(u32) => "32-bit unsigned integer value",
+    UInt64(u64) => "64-bit unsigned integer value",
+    UInt128(u128) => "128-bit unsigned integer value",
+    Float32(f32) => "32-bit floating point value",
+    Float64(f64) => "64-bit floating point value",
+    String(String) => "String value",
+    Date(NaiveDate) => "Date value (NaiveDate)",
+    Time(NaiveTime) => "Time value (NaiveTime)",
+    DateTime(NaiveDateTime) => "DateTime value (NaiveDateTime)",
+    Instant(DateTime<Utc>) => "UTC time point value (`DateTime<Utc>`)",
+    BigInteger(BigInt) => "Big integer value (BigInt)",
+    BigDecimal(BigDecimal) => "Big decimal value (BigDecimal)",
+}
+
+impl From<&str> for TypedValue {
+    fn from(value: &str) -> Self {
+        TypedValue::String(value.to_string())
+    }
+}
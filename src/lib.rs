@@ -34,33 +34,73 @@ pub use lang::{
         check_position_indexes,
         check_state,
         check_state_with_message,
+        check_that,
+        // Integer functions
+        checked_div_validated,
+        from_option,
+        get_checked,
+        // Result combinators
+        group_by_parameter,
+        // Numeric functions
+        parse_named,
+        require,
         // Collection functions
+        require_all_some,
+        require_date_in_range,
         require_element_non_null,
-        // Numeric functions
+        require_enum,
         require_equal,
+        require_equal_within,
+        require_length_in_range_owned,
+        require_length_relation,
+        require_member,
+        require_non_empty_owned,
         require_not_equal,
         // Option functions
         require_null_or,
+        require_ordered,
+        require_same_length,
+        require_sum_approx_equals,
+        require_sum_equals,
+        zip_results3,
         ArgumentError,
         ArgumentResult,
+        ArgumentResultExt,
+        Bounded,
+        CheckedArithmetic,
         CollectionArgument,
+        FloatArgument,
+        FloatSliceArgument,
+        IntegerArgument,
+        IntegerSliceArgument,
+        NonBlankString,
         NumericArgument,
         OptionArgument,
+        PathArgument,
+        SignedIntegerArgument,
+        SortOrder,
         // String functions
         StringArgument,
+        StringCollectionArgument,
+        VecArgument,
     },
     box_error::{
+        require_arg,
         BoxError,
         BoxResult,
     },
     data_type::{
+        require_byte_len_for,
         DataType,
         DataTypeOf,
     },
+    typed_value::TypedValue,
 };
 
 // Re-export utility types
 pub use util::{
+    patterns,
+    serde_as,
     Pair,
     Triple,
 };
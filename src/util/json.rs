@@ -0,0 +1,170 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # JSON Value Validation
+//!
+//! Provides functions validating the type of dynamic `serde_json::Value`s, e.g.
+//! values read from configuration files, before extracting them.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use crate::lang::{
+    argument::{
+        ArgumentError,
+        ArgumentResult,
+    },
+    data_type::json_kind,
+};
+use serde_json::{
+    Map,
+    Number,
+    Value,
+};
+
+/// Validate that a JSON value is a string
+///
+/// # Parameters
+///
+/// * `name` - Parameter name
+/// * `value` - JSON value to validate
+///
+/// # Returns
+///
+/// Returns a reference to the string if the value is a JSON string, otherwise
+/// returns an error describing the expected and the actual JSON type
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::util::require_json_string;
+/// use serde_json::json;
+///
+/// assert_eq!(require_json_string("host", &json!("localhost")).unwrap(), "localhost");
+/// assert!(require_json_string("host", &json!(8080)).is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub fn require_json_string<'a>(name: &str, value: &'a Value) -> ArgumentResult<&'a str> {
+    value
+        .as_str()
+        .ok_or_else(|| json_type_error(name, "string", value))
+}
+
+/// Validate that a JSON value is a number
+///
+/// # Parameters
+///
+/// * `name` - Parameter name
+/// * `value` - JSON value to validate
+///
+/// # Returns
+///
+/// Returns a reference to the number if the value is a JSON number, otherwise
+/// returns an error describing the expected and the actual JSON type
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::util::require_json_number;
+/// use serde_json::json;
+///
+/// assert_eq!(require_json_number("port", &json!(8080)).unwrap().as_u64(), Some(8080));
+/// assert!(require_json_number("port", &json!("8080")).is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub fn require_json_number<'a>(name: &str, value: &'a Value) -> ArgumentResult<&'a Number> {
+    match value {
+        Value::Number(number) => Ok(number),
+        _ => Err(json_type_error(name, "number", value)),
+    }
+}
+
+/// Validate that a JSON value is an array
+///
+/// # Parameters
+///
+/// * `name` - Parameter name
+/// * `value` - JSON value to validate
+///
+/// # Returns
+///
+/// Returns a reference to the elements if the value is a JSON array, otherwise
+/// returns an error describing the expected and the actual JSON type
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::util::require_json_array;
+/// use serde_json::json;
+///
+/// assert_eq!(require_json_array("hosts", &json!(["a", "b"])).unwrap().len(), 2);
+/// assert!(require_json_array("hosts", &json!("a")).is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub fn require_json_array<'a>(name: &str, value: &'a Value) -> ArgumentResult<&'a Vec<Value>> {
+    value
+        .as_array()
+        .ok_or_else(|| json_type_error(name, "array", value))
+}
+
+/// Validate that a JSON value is an object
+///
+/// # Parameters
+///
+/// * `name` - Parameter name
+/// * `value` - JSON value to validate
+///
+/// # Returns
+///
+/// Returns a reference to the entries if the value is a JSON object, otherwise
+/// returns an error describing the expected and the actual JSON type
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::util::require_json_object;
+/// use serde_json::json;
+///
+/// let config = json!({ "port": 8080 });
+/// assert!(require_json_object("config", &config).unwrap().contains_key("port"));
+/// assert!(require_json_object("config", &json!(null)).is_err());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub fn require_json_object<'a>(
+    name: &str,
+    value: &'a Value,
+) -> ArgumentResult<&'a Map<String, Value>> {
+    value
+        .as_object()
+        .ok_or_else(|| json_type_error(name, "object", value))
+}
+
+/// Creates the error for a JSON value that is not of the expected type
+fn json_type_error(name: &str, expected: &str, value: &Value) -> ArgumentError {
+    ArgumentError::for_param(
+        name,
+        &format!(
+            "must be a JSON {} but was a JSON {}",
+            expected,
+            json_kind(value)
+        ),
+    )
+}
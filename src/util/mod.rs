@@ -14,9 +14,23 @@
 //!
 //! Haixing Hu
 
+pub mod json;
+pub mod patterns;
+pub mod serde_as;
 pub mod tuple;
 
+pub use json::{
+    require_json_array,
+    require_json_number,
+    require_json_object,
+    require_json_string,
+};
 pub use tuple::{
+    map_to_pairs,
+    pairs_to_map,
+    require_max_depth,
+    NestedDepth,
     Pair,
+    PairSliceArgument,
     Triple,
 };
@@ -0,0 +1,140 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Common Patterns
+//!
+//! Provides documented regular expressions for common string formats, together
+//! with accessors returning lazily compiled `Regex`es that are shared by all
+//! callers. The accessors can be passed directly to `StringArgument::require_match`
+//! without recompiling the pattern on every call.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use prism3_core::{patterns, StringArgument};
+//!
+//! assert!("my-first-post".require_match("slug", patterns::slug()).is_ok());
+//! ```
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Regular expression of a reasonable email address
+///
+/// The local part may contain ASCII letters, digits and the characters
+/// ``.!#$%&'*+/=?^_`{|}~-``. The domain must consist of at least two dot-separated
+/// labels of ASCII letters, digits and inner hyphens, each at most 63 characters
+/// long.
+///
+/// # Limitations
+///
+/// This pattern is deliberately stricter and simpler than RFC 5322. It rejects
+/// some valid addresses, such as quoted local parts (`"john doe"@example.com`),
+/// IP address literals (`john@[192.168.0.1]`), internationalized addresses and
+/// addresses on top-level domains without a dot (`john@localhost`). It does not
+/// check the overall length limits, and a match does not mean that the address
+/// exists.
+pub const EMAIL: &str = r"^[A-Za-z0-9.!#$%&'*+/=?^_`{|}~-]+@[A-Za-z0-9](?:[A-Za-z0-9-]{0,61}[A-Za-z0-9])?(?:\.[A-Za-z0-9](?:[A-Za-z0-9-]{0,61}[A-Za-z0-9])?)+$";
+
+/// Regular expression of an IPv4 address in dotted-decimal notation
+///
+/// Each of the four octets must be in the range 0 to 255 and must not have
+/// leading zeros, e.g. `192.168.0.1` matches but `192.168.00.1` does not.
+pub const IPV4: &str = r"^(?:(?:25[0-5]|2[0-4][0-9]|1[0-9]{2}|[1-9]?[0-9])\.){3}(?:25[0-5]|2[0-4][0-9]|1[0-9]{2}|[1-9]?[0-9])$";
+
+/// Regular expression of a URL slug
+///
+/// A slug consists of lowercase ASCII letters and digits, optionally separated
+/// by single hyphens, e.g. `my-first-post`.
+pub const SLUG: &str = r"^[a-z0-9]+(?:-[a-z0-9]+)*$";
+
+/// Regular expression of a CSS hexadecimal color
+///
+/// Accepts a `#` followed by 3, 4, 6 or 8 hexadecimal digits of either case,
+/// e.g. `#fff` or `#1E90FF80`.
+pub const HEX_COLOR: &str = r"^#(?:[0-9A-Fa-f]{3,4}|[0-9A-Fa-f]{6}|[0-9A-Fa-f]{8})$";
+
+/// Regular expression of a UUID in its canonical hyphenated form
+///
+/// Accepts 32 hexadecimal digits of either case grouped as 8-4-4-4-12. The
+/// version and variant bits are not checked.
+pub const UUID: &str =
+    r"^[0-9A-Fa-f]{8}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{12}$";
+
+/// Regular expression of a semantic version
+///
+/// Follows the Semantic Versioning 2.0.0 grammar: `MAJOR.MINOR.PATCH` without
+/// leading zeros, optionally followed by a pre-release (`-alpha.1`) and build
+/// metadata (`+build.5`).
+pub const SEMVER: &str = r"^(?:0|[1-9][0-9]*)\.(?:0|[1-9][0-9]*)\.(?:0|[1-9][0-9]*)(?:-(?:0|[1-9][0-9]*|[0-9]*[A-Za-z-][0-9A-Za-z-]*)(?:\.(?:0|[1-9][0-9]*|[0-9]*[A-Za-z-][0-9A-Za-z-]*))*)?(?:\+[0-9A-Za-z-]+(?:\.[0-9A-Za-z-]+)*)?$";
+
+/// Compiles a pattern of this module on first use and caches it in `cell`.
+fn compiled(cell: &'static OnceLock<Regex>, pattern: &str) -> &'static Regex {
+    cell.get_or_init(|| Regex::new(pattern).expect("built-in patterns are valid regexes"))
+}
+
+/// Returns the compiled `EMAIL` pattern.
+///
+/// The pattern is compiled on first use and shared afterwards.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use prism3_core::patterns;
+///
+/// assert!(patterns::email().is_match("john.doe@example.com"));
+/// assert!(!patterns::email().is_match("john.doe"));
+/// ```
+pub fn email() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    compiled(&REGEX, EMAIL)
+}
+
+/// Returns the compiled `IPV4` pattern.
+///
+/// The pattern is compiled on first use and shared afterwards.
+pub fn ipv4() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    compiled(&REGEX, IPV4)
+}
+
+/// Returns the compiled `SLUG` pattern.
+///
+/// The pattern is compiled on first use and shared afterwards.
+pub fn slug() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    compiled(&REGEX, SLUG)
+}
+
+/// Returns the compiled `HEX_COLOR` pattern.
+///
+/// The pattern is compiled on first use and shared afterwards.
+pub fn hex_color() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    compiled(&REGEX, HEX_COLOR)
+}
+
+/// Returns the compiled `UUID` pattern.
+///
+/// The pattern is compiled on first use and shared afterwards.
+pub fn uuid() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    compiled(&REGEX, UUID)
+}
+
+/// Returns the compiled `SEMVER` pattern.
+///
+/// The pattern is compiled on first use and shared afterwards.
+pub fn semver() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    compiled(&REGEX, SEMVER)
+}
@@ -0,0 +1,107 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Serde Representations
+//!
+//! Provides alternative serde representations for use with
+//! `#[serde(with = "...")]`.
+//!
+//! By default `Pair` and `Triple` are serialized as objects with the fields
+//! `first`, `second` (and `third`). The modules of this file serialize them as
+//! arrays instead, which interoperates with array-based schemas.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use prism3_core::Pair;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Segment {
+//!     #[serde(with = "prism3_core::serde_as::pair_as_array")]
+//!     range: Pair<i32, i32>,
+//! }
+//!
+//! let segment = Segment { range: Pair::new(1, 2) };
+//! assert_eq!(serde_json::to_string(&segment).unwrap(), r#"{"range":[1,2]}"#);
+//! ```
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+/// Serializes a `Pair` as a two-element array `[first, second]`.
+///
+/// Deserialization accepts only the array representation.
+pub mod pair_as_array {
+    use crate::util::Pair;
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+    };
+
+    /// Serializes the pair as a two-element array.
+    pub fn serialize<F, S, Ser>(pair: &Pair<F, S>, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        F: Serialize,
+        S: Serialize,
+        Ser: Serializer,
+    {
+        pair.as_ref_tuple().serialize(serializer)
+    }
+
+    /// Deserializes the pair from a two-element array.
+    pub fn deserialize<'de, F, S, D>(deserializer: D) -> Result<Pair<F, S>, D::Error>
+    where
+        F: Deserialize<'de>,
+        S: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        <(F, S)>::deserialize(deserializer).map(Pair::from)
+    }
+}
+
+/// Serializes a `Triple` as a three-element array `[first, second, third]`.
+///
+/// Deserialization accepts only the array representation.
+pub mod triple_as_array {
+    use crate::util::Triple;
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+    };
+
+    /// Serializes the triple as a three-element array.
+    pub fn serialize<F, S, T, Ser>(
+        triple: &Triple<F, S, T>,
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error>
+    where
+        F: Serialize,
+        S: Serialize,
+        T: Serialize,
+        Ser: Serializer,
+    {
+        triple.as_ref_tuple().serialize(serializer)
+    }
+
+    /// Deserializes the triple from a three-element array.
+    pub fn deserialize<'de, F, S, T, D>(deserializer: D) -> Result<Triple<F, S, T>, D::Error>
+    where
+        F: Deserialize<'de>,
+        S: Deserialize<'de>,
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        <(F, S, T)>::deserialize(deserializer).map(Triple::from)
+    }
+}
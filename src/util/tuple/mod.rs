@@ -14,8 +14,18 @@
 //!
 //! Haixing Hu
 
+pub mod nested_depth;
 pub mod pair;
 pub mod triple;
 
-pub use pair::Pair;
+pub use nested_depth::{
+    require_max_depth,
+    NestedDepth,
+};
+pub use pair::{
+    map_to_pairs,
+    pairs_to_map,
+    Pair,
+    PairSliceArgument,
+};
 pub use triple::Triple;
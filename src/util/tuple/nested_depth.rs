@@ -0,0 +1,125 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Nested Depth
+//!
+//! Provides the nesting depth of tree structures built from nested pairs and
+//! triples, and a validator limiting it.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use super::{
+    pair::Pair,
+    triple::Triple,
+};
+use crate::lang::argument::{
+    ArgumentError,
+    ArgumentResult,
+};
+
+/// Nesting depth of a value
+///
+/// Scalar values have a depth of 1, while a `Pair` or `Triple` has a depth of 1
+/// plus the maximum depth of its elements.
+///
+/// # Examples
+///
+/// ```
+/// use prism3_core::util::NestedDepth;
+/// use prism3_core::Pair;
+///
+/// assert_eq!(42.nested_depth(), 1);
+/// assert_eq!(Pair::new(1, 2).nested_depth(), 2);
+/// assert_eq!(Pair::new(1, Pair::new(2, 3)).nested_depth(), 3);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub trait NestedDepth {
+    /// Returns the nesting depth of the value.
+    fn nested_depth(&self) -> usize;
+}
+
+macro_rules! impl_scalar_nested_depth {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl NestedDepth for $ty {
+                #[inline]
+                fn nested_depth(&self) -> usize {
+                    1
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_nested_depth!(
+    bool, char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, String,
+    &str,
+);
+
+impl<F: NestedDepth, S: NestedDepth> NestedDepth for Pair<F, S> {
+    #[inline]
+    fn nested_depth(&self) -> usize {
+        1 + self.first.nested_depth().max(self.second.nested_depth())
+    }
+}
+
+impl<F: NestedDepth, S: NestedDepth, T: NestedDepth> NestedDepth for Triple<F, S, T> {
+    #[inline]
+    fn nested_depth(&self) -> usize {
+        1 + self
+            .first
+            .nested_depth()
+            .max(self.second.nested_depth())
+            .max(self.third.nested_depth())
+    }
+}
+
+/// Validates that the nesting depth of a value does not exceed a maximum.
+///
+/// This guards code that processes nested pairs and triples recursively against
+/// excessively deep structures.
+///
+/// # Arguments
+///
+/// * `value` - The value to validate
+/// * `name` - The parameter name used in the error message
+/// * `max` - The maximum allowed nesting depth (inclusive)
+///
+/// # Returns
+///
+/// Returns `Ok(())` if the nesting depth of `value` is at most `max`, otherwise
+/// returns an error
+///
+/// # Examples
+///
+/// ```
+/// use prism3_core::util::require_max_depth;
+/// use prism3_core::Pair;
+///
+/// let tree = Pair::new(1, Pair::new(2, 3));
+/// assert!(require_max_depth(&tree, "tree", 3).is_ok());
+/// assert!(require_max_depth(&tree, "tree", 2).is_err());
+/// ```
+pub fn require_max_depth(value: &impl NestedDepth, name: &str, max: usize) -> ArgumentResult<()> {
+    let depth = value.nested_depth();
+    if depth > max {
+        return Err(ArgumentError::for_param(
+            name,
+            &format!(
+                "has nesting depth {} which exceeds the maximum of {}",
+                depth, max
+            ),
+        ));
+    }
+    Ok(())
+}
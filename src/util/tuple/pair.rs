@@ -38,7 +38,19 @@
 //!
 //! Haixing Hu
 
+use crate::lang::argument::{
+    ArgumentError,
+    ArgumentResult,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt;
+use std::hash::Hash;
+use std::ops::Sub;
 
 /// A generic pair structure that holds two values.
 ///
@@ -98,7 +110,7 @@ use std::fmt;
 /// assert_eq!(pair.first, 1);
 /// assert_eq!(pair.second, 2.5);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub struct Pair<F, S> {
     /// The first element of the pair
     pub first: F,
@@ -145,6 +157,45 @@ impl<F, S> Pair<F, S> {
         (self.first, self.second)
     }
 
+    /// Returns a tuple of references to the elements.
+    ///
+    /// This is a zero-cost view for passing the pair to tuple-based APIs without
+    /// consuming it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Pair;
+    ///
+    /// let pair = Pair::new(String::from("key"), 1);
+    /// let (first, second) = pair.as_ref_tuple();
+    /// assert_eq!(first, "key");
+    /// assert_eq!(*second, 1);
+    /// ```
+    #[inline]
+    pub fn as_ref_tuple(&self) -> (&F, &S) {
+        (&self.first, &self.second)
+    }
+
+    /// Returns a tuple of copies of the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Pair;
+    ///
+    /// let pair = Pair::new(1, 2.5);
+    /// assert_eq!(pair.as_tuple_copy(), (1, 2.5));
+    /// ```
+    #[inline]
+    pub fn as_tuple_copy(&self) -> (F, S)
+    where
+        F: Copy,
+        S: Copy,
+    {
+        (self.first, self.second)
+    }
+
     /// Returns a reference to the first element.
     ///
     /// # Examples
@@ -274,6 +325,370 @@ impl<F, S> Pair<F, S> {
     }
 }
 
+impl<K, V> Pair<K, V> {
+    /// Consumes the pair and returns it as a map entry, i.e. a `(key, value)` tuple.
+    ///
+    /// This is equivalent to `into_tuple`, but reads better when the pair is
+    /// used as a key-value entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Pair;
+    /// use std::collections::HashMap;
+    ///
+    /// let map: HashMap<&str, i32> = [Pair::new("a", 1)].into_iter().map(Pair::into_entry).collect();
+    /// assert_eq!(map["a"], 1);
+    /// ```
+    #[inline]
+    pub fn into_entry(self) -> (K, V) {
+        (self.first, self.second)
+    }
+
+    /// Inserts the pair into a map, using the first element as key and the
+    /// second element as value.
+    ///
+    /// # Arguments
+    ///
+    /// * `map` - The map to insert into
+    ///
+    /// # Returns
+    ///
+    /// Returns the value previously associated with the key, if any
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Pair;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map = HashMap::new();
+    /// assert_eq!(Pair::new("a", 1).insert_into(&mut map), None);
+    /// assert_eq!(Pair::new("a", 2).insert_into(&mut map), Some(1));
+    /// ```
+    #[inline]
+    pub fn insert_into(self, map: &mut HashMap<K, V>) -> Option<V>
+    where
+        K: Eq + Hash,
+    {
+        map.insert(self.first, self.second)
+    }
+}
+
+impl<T> Pair<T, T> {
+    /// Converts the pair into an array of its elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Pair;
+    ///
+    /// assert_eq!(Pair::new(1, 2).to_array(), [1, 2]);
+    /// ```
+    #[inline]
+    pub fn to_array(self) -> [T; 2] {
+        [self.first, self.second]
+    }
+
+    /// Reduces the elements of the pair, in order, with an accumulator.
+    ///
+    /// # Arguments
+    ///
+    /// * `init` - The initial value of the accumulator
+    /// * `f` - The function combining the accumulator with each element
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Pair;
+    ///
+    /// assert_eq!(Pair::new(3, 4).fold(0, |sum, x| sum + x), 7);
+    /// ```
+    #[inline]
+    pub fn fold<B, G>(self, init: B, f: G) -> B
+    where
+        G: FnMut(B, T) -> B,
+    {
+        self.to_array().into_iter().fold(init, f)
+    }
+}
+
+impl<T> Pair<T, T>
+where
+    T: Copy + PartialOrd + Sub<Output = T>,
+{
+    /// Returns `true` if both elements are within `epsilon` of the
+    /// corresponding elements of `other`.
+    ///
+    /// This is mainly intended for comparing computed `f32`/`f64` coordinates
+    /// in tests, where exact floating-point equality is unreliable. A `NaN`
+    /// element is never considered approximately equal to anything.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The pair to compare with
+    /// * `epsilon` - The maximum allowed absolute difference (inclusive)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Pair;
+    ///
+    /// let pair = Pair::new(0.1 + 0.2, 1.0);
+    /// assert!(pair.approx_eq(&Pair::new(0.3, 1.0), 1e-9));
+    /// assert!(!pair.approx_eq(&Pair::new(0.4, 1.0), 1e-9));
+    /// ```
+    #[inline]
+    pub fn approx_eq(&self, other: &Self, epsilon: T) -> bool {
+        within_epsilon(self.first, other.first, epsilon)
+            && within_epsilon(self.second, other.second, epsilon)
+    }
+}
+
+impl<T> Pair<T, T>
+where
+    T: PartialOrd + Default + fmt::Display + Copy,
+{
+    /// Validates that both elements are positive.
+    ///
+    /// This is intended for dimensions such as `(width, height)` stored as pairs.
+    /// The first non-positive element is reported by its field name. Values that
+    /// cannot be compared with zero, such as `NaN`, are rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The parameter name used in the error message
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if both elements are positive, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Pair;
+    ///
+    /// assert!(Pair::new(640u32, 480u32).require_all_positive("size").is_ok());
+    /// assert!(Pair::new(640u32, 0u32).require_all_positive("size").is_err());
+    /// ```
+    pub fn require_all_positive(&self, name: &str) -> ArgumentResult<&Self> {
+        for (field, value) in [("first", self.first), ("second", self.second)] {
+            if value.partial_cmp(&T::default()) != Some(Ordering::Greater) {
+                return Err(ArgumentError::for_param(
+                    name,
+                    &format!("field '{}' must be positive but was: {}", field, value),
+                ));
+            }
+        }
+        Ok(self)
+    }
+
+    /// Validates that both elements are non-negative.
+    ///
+    /// The first negative element is reported by its field name. Values that cannot
+    /// be compared with zero, such as `NaN`, are rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The parameter name used in the error message
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if both elements are non-negative, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Pair;
+    ///
+    /// assert!(Pair::new(0, 5).require_all_non_negative("offset").is_ok());
+    /// assert!(Pair::new(-1, 5).require_all_non_negative("offset").is_err());
+    /// ```
+    pub fn require_all_non_negative(&self, name: &str) -> ArgumentResult<&Self> {
+        for (field, value) in [("first", self.first), ("second", self.second)] {
+            if !matches!(
+                value.partial_cmp(&T::default()),
+                Some(Ordering::Greater | Ordering::Equal)
+            ) {
+                return Err(ArgumentError::for_param(
+                    name,
+                    &format!("field '{}' must be non-negative but was: {}", field, value),
+                ));
+            }
+        }
+        Ok(self)
+    }
+}
+
+impl Pair<f64, f64> {
+    /// Validates that both elements are finite, i.e. neither `NaN` nor infinite.
+    ///
+    /// This is intended for 2D points and vectors stored as pairs.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The parameter name used in the error message
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if both elements are finite, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Pair;
+    ///
+    /// assert!(Pair::new(1.0, 2.0).require_finite("point").is_ok());
+    /// assert!(Pair::new(f64::NAN, 1.0).require_finite("point").is_err());
+    /// ```
+    pub fn require_finite(&self, name: &str) -> ArgumentResult<&Self> {
+        if !self.first.is_finite() || !self.second.is_finite() {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "contains a non-finite component: ({:?}, {:?})",
+                    self.first, self.second
+                ),
+            ));
+        }
+        Ok(self)
+    }
+
+    /// Validates the pair as a `(numerator, denominator)` ratio and returns its value.
+    ///
+    /// This is intended for weights expressed as two numbers. The denominator must
+    /// be finite and non-zero, the numerator must be finite, and the quotient must
+    /// not overflow to infinity.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The parameter name used in the error message
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(first / second)` if the ratio is valid, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Pair;
+    ///
+    /// assert_eq!(Pair::new(1.0, 4.0).require_valid_ratio("weight").unwrap(), 0.25);
+    /// assert!(Pair::new(1.0, 0.0).require_valid_ratio("weight").is_err());
+    /// ```
+    pub fn require_valid_ratio(&self, name: &str) -> ArgumentResult<f64> {
+        if !self.second.is_finite() || self.second == 0.0 {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "denominator must be finite and non-zero but was: {:?}",
+                    self.second
+                ),
+            ));
+        }
+        if !self.first.is_finite() {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("numerator must be finite but was: {:?}", self.first),
+            ));
+        }
+        let ratio = self.first / self.second;
+        if !ratio.is_finite() {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "ratio {:?} / {:?} overflows to {:?}",
+                    self.first, self.second, ratio
+                ),
+            ));
+        }
+        Ok(ratio)
+    }
+}
+
+/// Validation of slices of pairs representing intervals.
+///
+/// Each pair is interpreted as an interval `[first, second]`, e.g. the start and
+/// end of a scheduled time slot.
+///
+/// # Examples
+///
+/// ```
+/// use prism3_core::util::PairSliceArgument;
+/// use prism3_core::Pair;
+///
+/// let slots = vec![Pair::new(9, 10), Pair::new(13, 15), Pair::new(10, 12)];
+/// assert!(slots.require_non_overlapping("slots").is_ok());
+///
+/// let slots = vec![Pair::new(9, 11), Pair::new(10, 12)];
+/// assert!(slots.require_non_overlapping("slots").is_err());
+/// ```
+pub trait PairSliceArgument {
+    /// Validates that the intervals are well-formed and do not overlap.
+    ///
+    /// Every interval must satisfy `start <= end`. The intervals need not be
+    /// sorted; they are compared in order of their starts. Intervals that only
+    /// share an endpoint, such as `[9, 10]` and `[10, 12]`, are adjacent and do
+    /// not overlap.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the collection used in error messages
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if all intervals are well-formed and pairwise disjoint,
+    /// otherwise returns an error reporting the first malformed interval or the
+    /// indices of the first overlapping intervals.
+    fn require_non_overlapping(&self, name: &str) -> ArgumentResult<&Self>;
+}
+
+impl<T> PairSliceArgument for [Pair<T, T>]
+where
+    T: Ord + Copy + fmt::Display,
+{
+    fn require_non_overlapping(&self, name: &str) -> ArgumentResult<&Self> {
+        if let Some(index) = self.iter().position(|p| p.first > p.second) {
+            return Err(ArgumentError::new(format!(
+                "Collection '{}': interval at index {} has start {} after end {}",
+                name, index, self[index].first, self[index].second
+            )));
+        }
+        let mut order: Vec<usize> = (0..self.len()).collect();
+        // Sorting by end as well makes the result independent of the input order
+        order.sort_by_key(|&index| (self[index].first, self[index].second));
+        // Index of the interval with the latest end among those seen so far
+        let mut latest: Option<usize> = None;
+        for index in order {
+            if let Some(prev) = latest {
+                if self[index].first < self[prev].second {
+                    let (a, b) = (prev.min(index), prev.max(index));
+                    return Err(ArgumentError::new(format!(
+                        "Collection '{}': intervals at indices {} and {} overlap: {} and {}",
+                        name, a, b, self[a], self[b]
+                    )));
+                }
+                if self[index].second > self[prev].second {
+                    latest = Some(index);
+                }
+            } else {
+                latest = Some(index);
+            }
+        }
+        Ok(self)
+    }
+}
+
+/// Returns `true` if the absolute difference of `a` and `b` is at most `epsilon`.
+#[inline]
+pub(crate) fn within_epsilon<T>(a: T, b: T, epsilon: T) -> bool
+where
+    T: Copy + PartialOrd + Sub<Output = T>,
+{
+    let diff = if a >= b { a - b } else { b - a };
+    diff <= epsilon
+}
+
 impl<F, S> From<(F, S)> for Pair<F, S> {
     /// Creates a `Pair` from a tuple.
     ///
@@ -295,6 +710,29 @@ impl<F, S> From<(F, S)> for Pair<F, S> {
     }
 }
 
+impl<T> From<(&str, T)> for Pair<String, T> {
+    /// Creates a labeled `Pair` from a tuple whose first element is a string slice.
+    ///
+    /// The string slice is copied into an owned `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Pair;
+    ///
+    /// let pair: Pair<String, i32> = ("count", 5).into();
+    /// assert_eq!(pair.first, "count");
+    /// assert_eq!(pair.second, 5);
+    /// ```
+    #[inline]
+    fn from(tuple: (&str, T)) -> Self {
+        Pair {
+            first: tuple.0.to_string(),
+            second: tuple.1,
+        }
+    }
+}
+
 impl<F, S> From<Pair<F, S>> for (F, S) {
     /// Converts a `Pair` into a tuple.
     ///
@@ -313,8 +751,115 @@ impl<F, S> From<Pair<F, S>> for (F, S) {
     }
 }
 
+impl<F: PartialEq, S: PartialEq> PartialEq<(F, S)> for Pair<F, S> {
+    /// Compares a `Pair` with a tuple element-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Pair;
+    ///
+    /// assert!(Pair::new(1, 2) == (1, 2));
+    /// assert!(Pair::new(1, 2) != (2, 1));
+    /// ```
+    #[inline]
+    fn eq(&self, other: &(F, S)) -> bool {
+        self.first == other.0 && self.second == other.1
+    }
+}
+
+impl<F: PartialEq, S: PartialEq> PartialEq<Pair<F, S>> for (F, S) {
+    /// Compares a tuple with a `Pair` element-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Pair;
+    ///
+    /// assert!((1, 2) == Pair::new(1, 2));
+    /// ```
+    #[inline]
+    fn eq(&self, other: &Pair<F, S>) -> bool {
+        self.0 == other.first && self.1 == other.second
+    }
+}
+
 impl<F: fmt::Display, S: fmt::Display> fmt::Display for Pair<F, S> {
+    /// Formats the pair as `(first, second)`.
+    ///
+    /// Formatter flags such as width and precision are applied to each element
+    /// rather than to the pair as a whole.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Pair;
+    ///
+    /// assert_eq!(format!("{:.2}", Pair::new(1.0, 2.0)), "(1.00, 2.00)");
+    /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "({}, {})", self.first, self.second)
+        f.write_str("(")?;
+        fmt::Display::fmt(&self.first, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.second, f)?;
+        f.write_str(")")
     }
 }
+
+/// Builds a `HashMap` from key-value pairs.
+///
+/// The `first` element of each pair is used as the key and the `second` element
+/// as the value. If several pairs have the same key, the value of the last one
+/// is kept.
+///
+/// # Arguments
+///
+/// * `pairs` - The key-value pairs
+///
+/// # Examples
+///
+/// ```
+/// use prism3_core::util::tuple::pairs_to_map;
+/// use prism3_core::Pair;
+///
+/// let map = pairs_to_map(vec![Pair::new("a", 1), Pair::new("b", 2), Pair::new("a", 3)]);
+/// assert_eq!(map.len(), 2);
+/// assert_eq!(map["a"], 3);
+/// assert_eq!(map["b"], 2);
+/// ```
+pub fn pairs_to_map<K, V, I>(pairs: I) -> HashMap<K, V>
+where
+    K: Eq + Hash,
+    I: IntoIterator<Item = Pair<K, V>>,
+{
+    pairs
+        .into_iter()
+        .map(|pair| (pair.first, pair.second))
+        .collect()
+}
+
+/// Drains a `HashMap` into key-value pairs.
+///
+/// The key of each entry becomes the `first` element and the value becomes the
+/// `second` element of a pair. The order of the returned pairs is the iteration
+/// order of the map, which is unspecified.
+///
+/// # Arguments
+///
+/// * `map` - The map to drain
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use prism3_core::util::tuple::map_to_pairs;
+/// use prism3_core::Pair;
+///
+/// let mut map = HashMap::new();
+/// map.insert("a", 1);
+/// let pairs = map_to_pairs(map);
+/// assert_eq!(pairs, vec![Pair::new("a", 1)]);
+/// ```
+pub fn map_to_pairs<K, V>(map: HashMap<K, V>) -> Vec<Pair<K, V>> {
+    map.into_iter().map(Pair::from).collect()
+}
@@ -14,7 +14,21 @@
 //!
 //! Haixing Hu
 
+use super::pair::{
+    within_epsilon,
+    Pair,
+};
+use crate::lang::argument::{
+    ArgumentError,
+    ArgumentResult,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::cmp::Ordering;
 use std::fmt;
+use std::ops::Sub;
 
 /// A generic triple structure that holds three values.
 ///
@@ -76,7 +90,7 @@ use std::fmt;
 /// assert_eq!(triple.second, 2.5);
 /// assert_eq!(triple.third, "hello");
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub struct Triple<F, S, T> {
     /// The first element of the triple
     pub first: F,
@@ -132,6 +146,47 @@ impl<F, S, T> Triple<F, S, T> {
         (self.first, self.second, self.third)
     }
 
+    /// Returns a tuple of references to the elements.
+    ///
+    /// This is a zero-cost view for passing the triple to tuple-based APIs without
+    /// consuming it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Triple;
+    ///
+    /// let triple = Triple::new(String::from("key"), 1, true);
+    /// let (first, second, third) = triple.as_ref_tuple();
+    /// assert_eq!(first, "key");
+    /// assert_eq!(*second, 1);
+    /// assert!(*third);
+    /// ```
+    #[inline]
+    pub fn as_ref_tuple(&self) -> (&F, &S, &T) {
+        (&self.first, &self.second, &self.third)
+    }
+
+    /// Returns a tuple of copies of the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Triple;
+    ///
+    /// let triple = Triple::new(1, 2.5, 'c');
+    /// assert_eq!(triple.as_tuple_copy(), (1, 2.5, 'c'));
+    /// ```
+    #[inline]
+    pub fn as_tuple_copy(&self) -> (F, S, T)
+    where
+        F: Copy,
+        S: Copy,
+        T: Copy,
+    {
+        (self.first, self.second, self.third)
+    }
+
     /// Returns a reference to the first element.
     ///
     /// # Examples
@@ -299,6 +354,260 @@ impl<F, S, T> Triple<F, S, T> {
             third: f(self.third),
         }
     }
+
+    /// Projects the first and second elements into a `Pair`, discarding the third.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::{Pair, Triple};
+    ///
+    /// let triple = Triple::new(1, "hello", true);
+    /// assert_eq!(triple.first_second(), Pair::new(1, "hello"));
+    /// ```
+    #[inline]
+    pub fn first_second(self) -> Pair<F, S> {
+        Pair::new(self.first, self.second)
+    }
+
+    /// Projects the first and third elements into a `Pair`, discarding the second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::{Pair, Triple};
+    ///
+    /// let triple = Triple::new(1, "hello", true);
+    /// assert_eq!(triple.first_third(), Pair::new(1, true));
+    /// ```
+    #[inline]
+    pub fn first_third(self) -> Pair<F, T> {
+        Pair::new(self.first, self.third)
+    }
+
+    /// Projects the second and third elements into a `Pair`, discarding the first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::{Pair, Triple};
+    ///
+    /// let triple = Triple::new(1, "hello", true);
+    /// assert_eq!(triple.second_third(), Pair::new("hello", true));
+    /// ```
+    #[inline]
+    pub fn second_third(self) -> Pair<S, T> {
+        Pair::new(self.second, self.third)
+    }
+}
+
+impl<T> Triple<T, T, T> {
+    /// Converts the triple into an array of its elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Triple;
+    ///
+    /// assert_eq!(Triple::new(1, 2, 3).to_array(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn to_array(self) -> [T; 3] {
+        [self.first, self.second, self.third]
+    }
+
+    /// Reduces the elements of the triple, in order, with an accumulator.
+    ///
+    /// # Arguments
+    ///
+    /// * `init` - The initial value of the accumulator
+    /// * `f` - The function combining the accumulator with each element
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Triple;
+    ///
+    /// assert_eq!(Triple::new(1, 2, 3).fold(0, |sum, x| sum + x), 6);
+    /// ```
+    #[inline]
+    pub fn fold<B, G>(self, init: B, f: G) -> B
+    where
+        G: FnMut(B, T) -> B,
+    {
+        self.to_array().into_iter().fold(init, f)
+    }
+}
+
+impl<T> Triple<T, T, T>
+where
+    T: Copy + PartialOrd + Sub<Output = T>,
+{
+    /// Returns `true` if all elements are within `epsilon` of the
+    /// corresponding elements of `other`.
+    ///
+    /// This is mainly intended for comparing computed `f32`/`f64` coordinates
+    /// in tests, where exact floating-point equality is unreliable. A `NaN`
+    /// element is never considered approximately equal to anything.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The triple to compare with
+    /// * `epsilon` - The maximum allowed absolute difference (inclusive)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Triple;
+    ///
+    /// let triple = Triple::new(0.1 + 0.2, 1.0, 2.0);
+    /// assert!(triple.approx_eq(&Triple::new(0.3, 1.0, 2.0), 1e-9));
+    /// assert!(!triple.approx_eq(&Triple::new(0.3, 1.0, 2.1), 1e-9));
+    /// ```
+    #[inline]
+    pub fn approx_eq(&self, other: &Self, epsilon: T) -> bool {
+        within_epsilon(self.first, other.first, epsilon)
+            && within_epsilon(self.second, other.second, epsilon)
+            && within_epsilon(self.third, other.third, epsilon)
+    }
+}
+
+impl Triple<f64, f64, f64> {
+    /// Validates that all elements are finite, i.e. neither `NaN` nor infinite.
+    ///
+    /// This is intended for 3D points and vectors stored as triples.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The parameter name used in the error message
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if all elements are finite, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Triple;
+    ///
+    /// assert!(Triple::new(1.0, 2.0, 3.0).require_finite("point").is_ok());
+    /// assert!(Triple::new(1.0, f64::INFINITY, 3.0).require_finite("point").is_err());
+    /// ```
+    pub fn require_finite(&self, name: &str) -> ArgumentResult<&Self> {
+        if !self.first.is_finite() || !self.second.is_finite() || !self.third.is_finite() {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "contains a non-finite component: ({:?}, {:?}, {:?})",
+                    self.first, self.second, self.third
+                ),
+            ));
+        }
+        Ok(self)
+    }
+
+    /// Validates that the quadratic with coefficients `(a, b, c)` has real roots.
+    ///
+    /// The triple is interpreted as the coefficients of `a*x² + b*x + c`, and the
+    /// discriminant `b² - 4ac` must be non-negative. All coefficients must be
+    /// finite, and so must the discriminant, which may overflow for huge
+    /// coefficients.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The parameter name used in the error message
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if the coefficients and the discriminant are finite and
+    /// the discriminant is non-negative, otherwise returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Triple;
+    ///
+    /// // x² - 3x + 2 = (x - 1)(x - 2)
+    /// assert!(Triple::new(1.0, -3.0, 2.0).require_discriminant_non_negative("coeffs").is_ok());
+    /// // x² + 1 has complex roots
+    /// assert!(Triple::new(1.0, 0.0, 1.0).require_discriminant_non_negative("coeffs").is_err());
+    /// ```
+    pub fn require_discriminant_non_negative(&self, name: &str) -> ArgumentResult<&Self> {
+        self.require_finite(name)?;
+        let discriminant = self.second * self.second - 4.0 * self.first * self.third;
+        if !discriminant.is_finite() {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!("has a non-finite discriminant: {:?}", discriminant),
+            ));
+        }
+        if discriminant < 0.0 {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "discriminant must be non-negative but was: {}",
+                    discriminant
+                ),
+            ));
+        }
+        Ok(self)
+    }
+}
+
+impl<T> Triple<T, T, T>
+where
+    T: PartialOrd + fmt::Display,
+{
+    /// Validates that the middle element lies between the outer elements.
+    ///
+    /// The triple is interpreted as `(lower, value, upper)`, a compact way to carry
+    /// a clamped value together with its bounds. Both bounds are inclusive.
+    /// Elements that cannot be compared (e.g. `NaN`) fail the validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The parameter name used in the error message
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(self)` if `first <= second <= third`, otherwise returns an error
+    /// naming the violated bound
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Triple;
+    ///
+    /// assert!(Triple::new(0, 5, 10).require_value_within("volume").is_ok());
+    /// assert!(Triple::new(0, 15, 10).require_value_within("volume").is_err());
+    /// ```
+    pub fn require_value_within(&self, name: &str) -> ArgumentResult<&Self> {
+        if !matches!(
+            self.first.partial_cmp(&self.second),
+            Some(Ordering::Less | Ordering::Equal)
+        ) {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "value {} must be greater than or equal to lower bound {}",
+                    self.second, self.first
+                ),
+            ));
+        }
+        if !matches!(
+            self.second.partial_cmp(&self.third),
+            Some(Ordering::Less | Ordering::Equal)
+        ) {
+            return Err(ArgumentError::for_param(
+                name,
+                &format!(
+                    "value {} must be less than or equal to upper bound {}",
+                    self.second, self.third
+                ),
+            ));
+        }
+        Ok(self)
+    }
 }
 
 impl<F, S, T> From<(F, S, T)> for Triple<F, S, T> {
@@ -342,8 +651,59 @@ impl<F, S, T> From<Triple<F, S, T>> for (F, S, T) {
     }
 }
 
+impl<F: PartialEq, S: PartialEq, T: PartialEq> PartialEq<(F, S, T)> for Triple<F, S, T> {
+    /// Compares a `Triple` with a tuple element-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Triple;
+    ///
+    /// assert!(Triple::new(1, 2, 3) == (1, 2, 3));
+    /// assert!(Triple::new(1, 2, 3) != (3, 2, 1));
+    /// ```
+    #[inline]
+    fn eq(&self, other: &(F, S, T)) -> bool {
+        self.first == other.0 && self.second == other.1 && self.third == other.2
+    }
+}
+
+impl<F: PartialEq, S: PartialEq, T: PartialEq> PartialEq<Triple<F, S, T>> for (F, S, T) {
+    /// Compares a tuple with a `Triple` element-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Triple;
+    ///
+    /// assert!((1, 2, 3) == Triple::new(1, 2, 3));
+    /// ```
+    #[inline]
+    fn eq(&self, other: &Triple<F, S, T>) -> bool {
+        self.0 == other.first && self.1 == other.second && self.2 == other.third
+    }
+}
+
 impl<F: fmt::Display, S: fmt::Display, T: fmt::Display> fmt::Display for Triple<F, S, T> {
+    /// Formats the triple as `(first, second, third)`.
+    ///
+    /// Formatter flags such as width and precision are applied to each element
+    /// rather than to the triple as a whole.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prism3_core::Triple;
+    ///
+    /// assert_eq!(format!("{:.1}", Triple::new(1.0, 2.0, 3.0)), "(1.0, 2.0, 3.0)");
+    /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "({}, {}, {})", self.first, self.second, self.third)
+        f.write_str("(")?;
+        fmt::Display::fmt(&self.first, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.second, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.third, f)?;
+        f.write_str(")")
     }
 }
@@ -0,0 +1,46 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+use prism3_core::Bounded;
+
+#[test]
+fn try_new_accepts_in_range_values() {
+    let volume = Bounded::try_new("volume", 50, 0, 100).unwrap();
+    assert_eq!(*volume, 50);
+    assert_eq!(volume.get(), 50);
+    assert_eq!(volume.min(), 0);
+    assert_eq!(volume.max(), 100);
+    assert_eq!(volume.into_inner(), 50);
+
+    // Bounds are inclusive
+    assert!(Bounded::try_new("volume", 0, 0, 100).is_ok());
+    assert!(Bounded::try_new("volume", 100, 0, 100).is_ok());
+
+    let ratio: Bounded<f64> = Bounded::try_new("ratio", 0.25, 0.0, 1.0).unwrap();
+    assert_eq!(format!("{:.1}", ratio), "0.2");
+    assert!(ratio.is_sign_positive());
+}
+
+#[test]
+fn try_new_rejects_out_of_range_values() {
+    let err = Bounded::try_new("volume", 150, 0, 100).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'volume' must be in range [0, 100] but was: 150"
+    );
+    assert!(Bounded::try_new("volume", -1, 0, 100).is_err());
+}
+
+#[test]
+fn try_new_rejects_inverted_bounds() {
+    let err = Bounded::try_new("volume", 50, 100, 0).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'volume' has invalid bounds: minimum 100 is greater than maximum 0"
+    );
+}
@@ -7,8 +7,16 @@
  *
  ******************************************************************************/
 use prism3_core::{
+    require_all_some,
     require_element_non_null,
+    require_length_in_range_owned,
+    require_length_relation,
+    require_member,
+    require_non_empty_owned,
+    require_same_length,
     CollectionArgument,
+    SortOrder,
+    VecArgument,
 };
 
 #[test]
@@ -69,3 +77,466 @@ fn require_element_non_null_checks() {
     let err2 = require_element_non_null("items", &none_first).unwrap_err();
     assert!(err2.message().contains("element at index 0"));
 }
+
+#[test]
+fn non_empty_len_returns_length() {
+    assert_eq!(vec![1, 2, 3].non_empty_len("v").unwrap(), 3);
+    assert_eq!([1].non_empty_len("v").unwrap(), 1);
+
+    let empty: Vec<i32> = vec![];
+    let err = empty.non_empty_len("v").unwrap_err();
+    assert!(err.message().contains("Collection 'v' cannot be empty"));
+    let empty_slice: [i32; 0] = [];
+    assert!(empty_slice.non_empty_len("v").is_err());
+}
+
+#[test]
+fn length_in_range_returns_length() {
+    let values = vec![1, 2, 3];
+    assert_eq!(values.length_in_range("v", 1, 5).unwrap(), 3);
+    assert_eq!(values.length_in_range("v", 3, 3).unwrap(), 3);
+    assert_eq!(values[..1].length_in_range("v", 0, 1).unwrap(), 1);
+
+    let err = values.length_in_range("v", 4, 5).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'v' length must be in range [4, 5] but was 3"
+    );
+    assert!(values.length_in_range("v", 0, 2).is_err());
+}
+
+#[test]
+fn find_invalid_returns_all_failing_indices() {
+    let values = vec![1, -2, 3, -4, -5];
+    assert_eq!(values.find_invalid(|&v| v > 0), vec![1, 3, 4]);
+    assert_eq!(values[..2].find_invalid(|&v| v > 0), vec![1]);
+    assert!(values.find_invalid(|&v| v > -10).is_empty());
+
+    let empty: Vec<i32> = vec![];
+    assert!(empty.find_invalid(|&v| v > 0).is_empty());
+}
+
+#[test]
+fn require_all_collecting_reports_every_failure() {
+    let values = vec![1, -2, 3, -4, -5];
+    let err = values
+        .require_all_collecting("values", |&v| v > 0, "must be positive")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'values': elements at indices [1, 3, 4] must be positive"
+    );
+
+    let positives = [1, 2, 3];
+    assert!(positives
+        .require_all_collecting("values", |&v| v > 0, "must be positive")
+        .is_ok());
+
+    let names = vec!["alice".to_string(), String::new(), "bob".to_string()];
+    let err = names
+        .require_all_collecting("names", |n| !n.is_empty(), "cannot be empty")
+        .unwrap_err();
+    assert!(err.message().contains("[1]"));
+}
+
+#[test]
+fn require_same_length_checks() {
+    let keys = vec!["a", "b", "c"];
+    let values = vec![1, 2, 3];
+    assert_eq!(
+        require_same_length("keys", &keys, "values", &values).unwrap(),
+        3
+    );
+
+    let empty_keys: Vec<&str> = vec![];
+    let empty_values: Vec<i32> = vec![];
+    assert_eq!(
+        require_same_length("keys", &empty_keys, "values", &empty_values).unwrap(),
+        0
+    );
+
+    let err = require_same_length("keys", &keys, "values", &values[..2]).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collections 'keys' (3) and 'values' (2) must have the same length"
+    );
+}
+
+#[test]
+fn first_and_rest_splits_head() {
+    assert_eq!([1, 2, 3].first_and_rest("v").unwrap(), (&1, &[2, 3][..]));
+    assert_eq!([7].first_and_rest("v").unwrap(), (&7, &[][..]));
+
+    let v = vec!["head".to_string(), "tail".to_string()];
+    let (first, rest) = v.first_and_rest("v").unwrap();
+    assert_eq!(first, "head");
+    assert_eq!(rest, ["tail".to_string()]);
+
+    let empty: Vec<i32> = vec![];
+    let err = empty.first_and_rest("v").unwrap_err();
+    assert_eq!(err.message(), "Collection 'v' cannot be empty");
+}
+
+#[test]
+fn require_length_relation_checks() {
+    let headers = ["id", "name"];
+    let cells = ["1", "Alice", "2", "Bob"];
+    let multiple = |h: usize, c: usize| h > 0 && c % h == 0;
+    let desc = "length of 'cells' must be a multiple of length of 'headers'";
+    assert!(require_length_relation("headers", &headers, "cells", &cells, multiple, desc).is_ok());
+
+    let err = require_length_relation("headers", &headers, "cells", &cells[..3], multiple, desc)
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collections 'headers' (2) and 'cells' (3) violate the length relation: \
+         length of 'cells' must be a multiple of length of 'headers'"
+    );
+
+    let at_most = |a: usize, b: usize| a <= b;
+    assert!(require_length_relation("a", &[1, 2], "b", &[1, 2, 3], at_most, "a <= b").is_ok());
+    assert!(require_length_relation("a", &[1, 2], "b", &[1, 2], at_most, "a <= b").is_ok());
+    let err = require_length_relation("a", &[1, 2, 3], "b", &[1], at_most, "a <= b").unwrap_err();
+    assert!(err.message().ends_with("a <= b"));
+}
+
+#[test]
+fn require_no_adjacent_repeats_checks() {
+    let abab: Vec<char> = "abab".chars().collect();
+    assert!(abab.require_no_adjacent_repeats("password").is_ok());
+
+    let aabb: Vec<char> = "aabb".chars().collect();
+    let err = aabb.require_no_adjacent_repeats("password").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'password' has equal adjacent elements at indices 0 and 1"
+    );
+
+    let abcc: Vec<char> = "abcc".chars().collect();
+    let err = abcc.require_no_adjacent_repeats("password").unwrap_err();
+    assert!(err.message().ends_with("at indices 2 and 3"));
+
+    let empty: [i32; 0] = [];
+    assert!(empty.require_no_adjacent_repeats("v").is_ok());
+    assert!([1].require_no_adjacent_repeats("v").is_ok());
+    assert!([1, 2, 1].require_no_adjacent_repeats("v").is_ok());
+}
+
+#[test]
+fn require_member_checks() {
+    let roles = ["admin", "user"];
+    assert!(require_member("role", &"admin", &roles).is_ok());
+    assert!(require_member("role", &"user", &roles).is_ok());
+
+    let err = require_member("role", &"guest", &roles).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Value 'role' (guest) is not among the allowed values"
+    );
+
+    let ports = vec![80, 443];
+    assert!(require_member("port", &443, &ports).is_ok());
+    assert!(require_member("port", &8080, &ports).is_err());
+    assert!(require_member("port", &80, &[]).is_err());
+}
+
+#[test]
+fn require_length_multiple_of_checks() {
+    assert!(vec![0u8; 12]
+        .require_length_multiple_of("pixels", 4)
+        .is_ok());
+    assert!([0u8; 0].require_length_multiple_of("pixels", 4).is_ok());
+    assert!([1, 2, 3].require_length_multiple_of("v", 1).is_ok());
+
+    let err = vec![0u8; 10]
+        .require_length_multiple_of("pixels", 4)
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'pixels' length (10) must be a multiple of 4"
+    );
+
+    let err = [1, 2].require_length_multiple_of("v", 0).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'v' length factor must be positive"
+    );
+}
+
+#[test]
+fn require_ordered_ascending() {
+    assert!([1, 2, 2, 3]
+        .require_ordered("v", SortOrder::Ascending)
+        .is_ok());
+    let err = [1, 3, 2]
+        .require_ordered("v", SortOrder::Ascending)
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'v' must be in ascending order but element at index 2 breaks it"
+    );
+}
+
+#[test]
+fn require_ordered_ascending_strict() {
+    assert!([1, 2, 3]
+        .require_ordered("v", SortOrder::AscendingStrict)
+        .is_ok());
+    let err = [1, 2, 2, 3]
+        .require_ordered("v", SortOrder::AscendingStrict)
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'v' must be in strictly ascending order but element at index 2 breaks it"
+    );
+}
+
+#[test]
+fn require_ordered_descending() {
+    assert!(vec![3, 2, 2, 1]
+        .require_ordered("v", SortOrder::Descending)
+        .is_ok());
+    let err = [3, 1, 2]
+        .require_ordered("v", SortOrder::Descending)
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'v' must be in descending order but element at index 2 breaks it"
+    );
+}
+
+#[test]
+fn require_ordered_descending_strict() {
+    assert!([3, 2, 1]
+        .require_ordered("v", SortOrder::DescendingStrict)
+        .is_ok());
+    let err = [3, 3, 1]
+        .require_ordered("v", SortOrder::DescendingStrict)
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'v' must be in strictly descending order but element at index 1 breaks it"
+    );
+}
+
+#[test]
+fn require_ordered_edge_cases() {
+    let empty: [i32; 0] = [];
+    assert!(empty
+        .require_ordered("v", SortOrder::AscendingStrict)
+        .is_ok());
+    assert!([7]
+        .require_ordered("v", SortOrder::DescendingStrict)
+        .is_ok());
+    // NaN is incomparable and therefore out of order
+    assert!([1.0, f64::NAN]
+        .require_ordered("v", SortOrder::Ascending)
+        .is_err());
+}
+
+#[test]
+fn require_all_some_borrows_values() {
+    let items = vec![Some("a".to_string()), Some("b".to_string())];
+    let values = require_all_some("items", &items).unwrap();
+    assert_eq!(values, vec!["a", "b"]);
+    // The caller keeps ownership of the collection
+    assert_eq!(items.len(), 2);
+
+    let empty: [Option<i32>; 0] = [];
+    assert!(require_all_some("items", &empty).unwrap().is_empty());
+}
+
+#[test]
+fn require_all_some_reports_none_in_middle() {
+    let items = [Some(1), None, Some(3)];
+    let err = require_all_some("items", &items).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'items': element at index 1 cannot be null"
+    );
+}
+
+#[test]
+fn require_subsequence_present() {
+    let steps = ["checkout", "build", "test", "deploy"];
+    assert!(steps
+        .require_subsequence("steps", &["build", "deploy"])
+        .is_ok());
+    assert!(steps.require_subsequence("steps", &steps).is_ok());
+    assert!(steps.require_subsequence("steps", &[]).is_ok());
+
+    let numbers = vec![1, 2, 1, 3];
+    assert!(numbers.require_subsequence("numbers", &[1, 1, 3]).is_ok());
+}
+
+#[test]
+fn require_subsequence_absent() {
+    let steps = ["checkout", "build", "test", "deploy"];
+    let err = steps
+        .require_subsequence("steps", &["build", "deploy", "test"])
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'steps' must contain the required elements in order but required element 2 ('test') is missing or out of order"
+    );
+    assert!(steps.require_subsequence("steps", &["lint"]).is_err());
+    assert!([1, 2].require_subsequence("numbers", &[1, 1]).is_err());
+}
+
+#[test]
+fn require_all_in_range_checks() {
+    assert!(vec![1u8, 2, 3].require_all_in_range("data", 0, 10).is_ok());
+    assert!([0u8, 10].require_all_in_range("data", 0, 10).is_ok());
+    let empty: [u8; 0] = [];
+    assert!(empty.require_all_in_range("data", 0, 10).is_ok());
+
+    let err = vec![1u8, 2, 30, 40]
+        .require_all_in_range("data", 0, 10)
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'data': element at index 2 must be in range [0, 10] but was: 30"
+    );
+    assert!([0.5, f64::NAN]
+        .require_all_in_range("ratios", 0.0, 1.0)
+        .is_err());
+}
+
+#[test]
+fn require_capacity_at_most_checks() {
+    let v: Vec<u8> = Vec::with_capacity(16);
+    assert!(v.require_capacity_at_most("v", 16).is_ok());
+    assert!(v.require_capacity_at_most("v", 100).is_ok());
+
+    let err = v.require_capacity_at_most("v", 8).unwrap_err();
+    assert_eq!(
+        err.message(),
+        format!(
+            "Collection 'v' capacity must be at most 8 but was {} (length 0)",
+            v.capacity()
+        )
+    );
+}
+
+#[test]
+fn require_capacity_ratio_at_most_checks() {
+    let mut v: Vec<u8> = Vec::with_capacity(4);
+    v.extend([1, 2, 3, 4]);
+    assert!(v.require_capacity_ratio_at_most("v", 1).is_ok());
+    assert!(Vec::<u8>::new()
+        .require_capacity_ratio_at_most("v", 2)
+        .is_ok());
+
+    let mut v: Vec<u8> = Vec::with_capacity(100);
+    v.push(1);
+    let err = v.require_capacity_ratio_at_most("v", 2).unwrap_err();
+    assert_eq!(
+        err.message(),
+        format!(
+            "Collection 'v' capacity must be at most 2 times its length but was {} (length 1)",
+            v.capacity()
+        )
+    );
+}
+
+#[test]
+fn require_count_matching_within_bounds() {
+    let flags = vec![true, false, true, true];
+    assert!(flags.require_count_matching("flags", |f| *f, 2, 5).is_ok());
+    // Both bounds are inclusive
+    assert!(flags.require_count_matching("flags", |f| *f, 3, 3).is_ok());
+    assert!(flags.require_count_matching("flags", |f| !*f, 0, 1).is_ok());
+}
+
+#[test]
+fn require_count_matching_outside_bounds() {
+    let flags = [true, false, false];
+    let err = flags
+        .require_count_matching("flags", |f| *f, 2, 5)
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'flags': expected between 2 and 5 matching elements but found 1"
+    );
+
+    let numbers = [1, 2, 3, 4, 5, 6];
+    assert!(numbers
+        .require_count_matching("numbers", |n| n % 2 == 0, 0, 2)
+        .is_err());
+}
+
+#[test]
+fn require_distinct_by_uses_custom_equality() {
+    let approx_eq = |a: &f64, b: &f64| (a - b).abs() < 1e-9;
+    assert!([0.1, 0.2, 0.3].require_distinct_by("v", approx_eq).is_ok());
+    assert!(Vec::<f64>::new()
+        .require_distinct_by("v", approx_eq)
+        .is_ok());
+
+    let values = vec![0.3, 1.0, 0.1 + 0.2, 1.0];
+    let err = values.require_distinct_by("v", approx_eq).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'v' must contain distinct elements but elements at indices 0 and 2 are equal"
+    );
+
+    let coarse_eq = |a: &f64, b: &f64| (a - b).abs() < 0.15;
+    let err = [0.0, 1.0, 1.1]
+        .require_distinct_by("v", coarse_eq)
+        .unwrap_err();
+    assert!(err.message().contains("indices 1 and 2"));
+}
+
+#[test]
+fn owned_validators_return_the_same_vec() {
+    let ids = require_non_empty_owned("ids", (1..=3).collect::<Vec<_>>()).unwrap();
+    assert_eq!(ids, [1, 2, 3]);
+
+    let mut buffer = Vec::with_capacity(16);
+    buffer.push(7u8);
+    let ptr = buffer.as_ptr();
+    let buffer = require_length_in_range_owned("buffer", buffer, 1, 4).unwrap();
+    assert_eq!(buffer.as_ptr(), ptr);
+    assert!(buffer.capacity() >= 16);
+}
+
+#[test]
+fn owned_validators_reject_invalid_vec() {
+    let err = require_non_empty_owned("ids", Vec::<i32>::new()).unwrap_err();
+    assert!(err.message().contains("Collection 'ids' cannot be empty"));
+
+    let err = require_length_in_range_owned("tags", vec!["a"], 2, 5).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'tags' length must be in range [2, 5] but was 1"
+    );
+}
+
+#[test]
+fn require_length_fits_in_narrow_integers() {
+    // Zero-sized elements make huge lengths free to allocate
+    let at_u16_max = vec![(); u16::MAX as usize];
+    assert!(at_u16_max.require_length_fits_in_u16("items").is_ok());
+    assert!(at_u16_max.require_length_fits_in_u32("items").is_ok());
+
+    let over_u16_max = vec![(); u16::MAX as usize + 1];
+    let err = over_u16_max
+        .require_length_fits_in_u16("items")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'items' length (65536) exceeds the maximum of 65535 for u16"
+    );
+    assert!(over_u16_max[..10]
+        .require_length_fits_in_u16("items")
+        .is_ok());
+    assert!(over_u16_max.require_length_fits_in_u32("items").is_ok());
+
+    let over_u32_max = vec![(); u32::MAX as usize + 1];
+    let err = over_u32_max
+        .require_length_fits_in_u32("items")
+        .unwrap_err();
+    assert!(err
+        .message()
+        .contains("exceeds the maximum of 4294967295 for u32"));
+}
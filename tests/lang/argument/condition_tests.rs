@@ -16,6 +16,10 @@ use prism3_core::{
     check_position_indexes,
     check_state,
     check_state_with_message,
+    check_that,
+    get_checked,
+    require,
+    ArgumentError,
 };
 
 #[test]
@@ -161,3 +165,68 @@ fn test_all_ok_branches() {
     assert!(check_state(true).is_ok());
     assert!(check_state_with_message(true, "any").is_ok());
 }
+
+#[test]
+fn check_that_returns_value_or_error() {
+    let x = 42;
+    assert_eq!(check_that(x, x > 0, "x must be positive").unwrap(), 42);
+
+    let s = String::from("owned");
+    let s = check_that(s, true, "unused").unwrap();
+    assert_eq!(s, "owned");
+
+    let y = -1;
+    let err = check_that(y, y > 0, "x must be positive").unwrap_err();
+    assert_eq!(err.message(), "x must be positive");
+}
+
+#[test]
+fn get_checked_returns_element() {
+    assert_eq!(get_checked(&[10, 20, 30], 1, "v").unwrap(), &20);
+    assert_eq!(get_checked(&[10, 20, 30], 0, "v").unwrap(), &10);
+    assert_eq!(get_checked(&[10, 20, 30], 2, "v").unwrap(), &30);
+
+    let names = vec!["a".to_string(), "b".to_string()];
+    assert_eq!(get_checked(&names, 1, "names").unwrap(), "b");
+}
+
+#[test]
+fn get_checked_rejects_out_of_range_index() {
+    let err = get_checked(&[10, 20, 30], 3, "v").unwrap_err();
+    assert_eq!(err.message(), "Collection 'v': Index 3 out of range [0, 3)");
+
+    let empty: [i32; 0] = [];
+    assert!(get_checked(&empty, 0, "v").is_err());
+}
+
+#[test]
+fn require_builds_error_lazily() {
+    let mut calls = 0;
+    let port = require(
+        8080,
+        |p| *p >= 1024,
+        |_| {
+            calls += 1;
+            ArgumentError::new("unused")
+        },
+    )
+    .unwrap();
+    assert_eq!(port, 8080);
+    assert_eq!(calls, 0);
+
+    let err = require(
+        80,
+        |p| *p >= 1024,
+        |p| ArgumentError::new(format!("port {} is reserved", p)),
+    )
+    .unwrap_err();
+    assert_eq!(err.message(), "port 80 is reserved");
+
+    let name = require(
+        String::from("alice"),
+        |s| !s.is_empty(),
+        |_| ArgumentError::new("name cannot be empty"),
+    )
+    .unwrap();
+    assert_eq!(name, "alice");
+}
@@ -7,8 +7,13 @@
  *
  ******************************************************************************/
 use prism3_core::{
+    group_by_parameter,
+    zip_results3,
     ArgumentError,
     ArgumentResult,
+    ArgumentResultExt,
+    NumericArgument,
+    StringArgument,
 };
 
 #[test]
@@ -32,6 +37,43 @@ fn argument_error_from_str_and_string() {
     assert_eq!(e2.message(), "Error B");
 }
 
+#[test]
+fn argument_error_for_param() {
+    let e = ArgumentError::for_param("port", "must be a privileged port");
+    assert_eq!(e.message(), "Parameter 'port' must be a privileged port");
+
+    // Built-in validators use the same format
+    let e = 0.require_positive("count").unwrap_err();
+    assert_eq!(
+        e,
+        ArgumentError::for_param("count", "must be positive but was: 0")
+    );
+    let e = "  ".require_non_blank("name").unwrap_err();
+    assert!(e.message().starts_with("Parameter 'name' "));
+}
+
+#[test]
+fn argument_error_new_checked() {
+    let e = ArgumentError::new_checked("Invalid parameter").unwrap();
+    assert_eq!(e.message(), "Invalid parameter");
+    let e = ArgumentError::new_checked(String::from("Owned")).unwrap();
+    assert_eq!(e.message(), "Owned");
+
+    let err = ArgumentError::new_checked("").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Argument error message cannot be empty or contain only whitespace characters"
+    );
+    assert!(ArgumentError::new_checked("  \t\n").is_err());
+}
+
+#[test]
+fn argument_error_default() {
+    let e = ArgumentError::default();
+    assert_eq!(e.message(), "invalid argument");
+    assert_eq!(e.to_string(), "invalid argument");
+}
+
 #[test]
 fn argument_result_usage() {
     fn validate_positive(v: i32) -> ArgumentResult<i32> {
@@ -46,3 +88,164 @@ fn argument_result_usage() {
     let err = validate_positive(0).unwrap_err();
     assert!(err.message().contains("Value must be positive"));
 }
+
+#[test]
+fn zip_results3_combines_successful_validations() {
+    struct Config {
+        port: i32,
+        host: String,
+        retries: i32,
+    }
+
+    fn build(port: i32, host: &str, retries: i32) -> ArgumentResult<Config> {
+        let (port, host, retries) = zip_results3(
+            port.require_in_closed_range("port", 1, 65535),
+            host.require_non_blank("host"),
+            retries.require_non_negative("retries"),
+        )?;
+        Ok(Config {
+            port,
+            host: host.to_string(),
+            retries,
+        })
+    }
+
+    let config = build(8080, "localhost", 3).unwrap();
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.host, "localhost");
+    assert_eq!(config.retries, 3);
+
+    assert!(build(0, "localhost", 3).is_err());
+    assert!(build(8080, "  ", 3).is_err());
+    assert!(build(8080, "localhost", -1).is_err());
+}
+
+#[test]
+fn zip_results3_returns_first_error() {
+    let result: ArgumentResult<(i32, i32, i32)> = zip_results3(
+        Ok(1),
+        Err(ArgumentError::new("second")),
+        Err(ArgumentError::new("third")),
+    );
+    assert_eq!(result.unwrap_err().message(), "second");
+
+    let result: ArgumentResult<(i32, i32, i32)> =
+        zip_results3(Ok(1), Ok(2), Err(ArgumentError::new("third")));
+    assert_eq!(result.unwrap_err().message(), "third");
+}
+
+#[test]
+fn tap_ok_runs_only_on_ok() {
+    let mut seen = None;
+    let result = 42
+        .require_positive("value")
+        .tap_ok(|value| seen = Some(*value))
+        .tap_err(|_| panic!("tap_err must not run on Ok"));
+    assert_eq!(result.unwrap(), 42);
+    assert_eq!(seen, Some(42));
+}
+
+#[test]
+fn tap_err_runs_only_on_err() {
+    let mut seen = None;
+    let result = (-1)
+        .require_positive("value")
+        .tap_ok(|_| panic!("tap_ok must not run on Err"))
+        .tap_err(|error| seen = Some(error.message().to_string()));
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Parameter 'value' must be positive but was: -1"
+    );
+    assert_eq!(
+        seen.as_deref(),
+        Some("Parameter 'value' must be positive but was: -1")
+    );
+}
+
+#[test]
+fn argument_error_parameter() {
+    let e = ArgumentError::for_param("port", "must be positive");
+    assert_eq!(e.parameter(), Some("port"));
+    assert_eq!(ArgumentError::new("Invalid parameter").parameter(), None);
+    assert_eq!(ArgumentError::from("Invalid parameter").parameter(), None);
+
+    // Built-in validators record the parameter name
+    let e = (-1).require_positive("count").unwrap_err();
+    assert_eq!(e.parameter(), Some("count"));
+}
+
+#[test]
+fn argument_error_equality_compares_messages_only() {
+    assert_eq!(
+        ArgumentError::new("Parameter 'port' must be positive"),
+        ArgumentError::for_param("port", "must be positive")
+    );
+    assert_ne!(
+        ArgumentError::for_param("port", "must be positive"),
+        ArgumentError::for_param("host", "must be positive")
+    );
+}
+
+#[test]
+fn group_by_parameter_groups_errors() {
+    let groups = group_by_parameter(vec![
+        ArgumentError::for_param("port", "must be positive"),
+        ArgumentError::for_param("host", "cannot be blank"),
+        ArgumentError::new("Invalid configuration"),
+        ArgumentError::for_param("port", "must be at most 65535"),
+    ]);
+    assert_eq!(groups.len(), 3);
+
+    let port_messages: Vec<&str> = groups[&Some("port".to_string())]
+        .iter()
+        .map(ArgumentError::message)
+        .collect();
+    assert_eq!(
+        port_messages,
+        vec![
+            "Parameter 'port' must be positive",
+            "Parameter 'port' must be at most 65535"
+        ]
+    );
+    assert_eq!(groups[&Some("host".to_string())].len(), 1);
+    assert_eq!(groups[&None][0].message(), "Invalid configuration");
+
+    assert!(group_by_parameter(Vec::new()).is_empty());
+}
+
+#[test]
+fn unwrap_or_default_with_warn_returns_valid_value() {
+    let mut warned = false;
+    let workers = 8
+        .require_positive("workers")
+        .unwrap_or_default_with_warn(4, |_| warned = true);
+    assert_eq!(workers, 8);
+    assert!(!warned);
+}
+
+#[test]
+fn unwrap_or_default_with_warn_falls_back_on_error() {
+    let mut warning = None;
+    let workers = 0
+        .require_positive("workers")
+        .unwrap_or_default_with_warn(4, |error| warning = Some(error.to_string()));
+    assert_eq!(workers, 4);
+    assert_eq!(
+        warning.as_deref(),
+        Some("Parameter 'workers' must be positive but was: 0")
+    );
+}
+
+#[test]
+fn into_option_logging_splits_result() {
+    let (value, error) = 8.require_positive("workers").into_option_logging();
+    assert_eq!(value, Some(8));
+    assert!(error.is_none());
+
+    let (value, error) = 0.require_positive("workers").into_option_logging();
+    assert!(value.is_none());
+    assert_eq!(
+        error.unwrap().message(),
+        "Parameter 'workers' must be positive but was: 0"
+    );
+}
@@ -0,0 +1,188 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+use prism3_core::{
+    FloatArgument,
+    FloatSliceArgument,
+    NumericArgument,
+};
+
+#[test]
+fn finite_accepts_regular_values() {
+    assert_eq!(1.5f64.require_finite("x").unwrap(), 1.5);
+    assert_eq!((-0.0f32).require_finite("x").unwrap(), 0.0);
+    assert_eq!(f64::MAX.require_finite("x").unwrap(), f64::MAX);
+    assert!(f64::MIN_POSITIVE.require_finite("x").is_ok());
+}
+
+#[test]
+fn finite_rejects_nan_and_infinity() {
+    let err = f64::NAN.require_finite("x").unwrap_err();
+    assert_eq!(err.message(), "Parameter 'x' must be finite but was: NaN");
+    let err = f32::INFINITY.require_finite("x").unwrap_err();
+    assert_eq!(err.message(), "Parameter 'x' must be finite but was: inf");
+    assert!(f64::NEG_INFINITY.require_finite("x").is_err());
+}
+
+#[test]
+fn finite_in_range_checks_both_conditions() {
+    assert_eq!(0.5f64.require_finite_in_range("x", 0.0, 1.0).unwrap(), 0.5);
+    assert!(0.0f64.require_finite_in_range("x", 0.0, 1.0).is_ok());
+    assert!(1.0f32.require_finite_in_range("x", 0.0, 1.0).is_ok());
+
+    let err = 1.5f64.require_finite_in_range("x", 0.0, 1.0).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'x' must be in range [0, 1] but was: 1.5"
+    );
+
+    let err = f64::INFINITY
+        .require_finite_in_range("x", 0.0, 1.0)
+        .unwrap_err();
+    assert!(err.message().contains("must be finite"));
+    assert!(f64::NEG_INFINITY
+        .require_finite_in_range("x", f64::NEG_INFINITY, 1.0)
+        .is_err());
+}
+
+#[test]
+fn finite_in_range_rejects_nan_unlike_closed_range() {
+    // NaN compares false with everything, so the plain range check lets it pass
+    assert!(f64::NAN.require_in_closed_range("x", 0.0, 1.0).is_ok());
+
+    let err = f64::NAN.require_finite_in_range("x", 0.0, 1.0).unwrap_err();
+    assert_eq!(err.message(), "Parameter 'x' must be finite but was: NaN");
+}
+
+#[test]
+fn all_finite_accepts_clean_data() {
+    assert!([1.0, 2.5, -3.0].require_all_finite("samples").is_ok());
+    assert!([0.5f32, f32::MAX].require_all_finite("samples").is_ok());
+    let empty: [f64; 0] = [];
+    assert!(empty.require_all_finite("samples").is_ok());
+
+    let samples: Vec<f64> = (1..=2).map(f64::from).collect();
+    assert_eq!(samples.require_all_finite("samples").unwrap(), &[1.0, 2.0]);
+}
+
+#[test]
+fn all_finite_reports_first_non_finite_index() {
+    let mut samples = [0.0; 10];
+    samples[7] = f64::NAN;
+    samples[9] = f64::INFINITY;
+    let err = samples.require_all_finite("samples").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'samples': element at index 7 is not finite (NaN)"
+    );
+
+    let err = [1.0f32, f32::NEG_INFINITY]
+        .require_all_finite("samples")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'samples': element at index 1 is not finite (-inf)"
+    );
+}
+
+#[test]
+fn require_sorted_floats_accepts_ascending() {
+    assert!([1.0, 2.0, 2.0, 3.5].require_sorted_floats("t").is_ok());
+    assert!([f32::NEG_INFINITY, 0.0, f32::INFINITY]
+        .require_sorted_floats("t")
+        .is_ok());
+    let empty: [f64; 0] = [];
+    assert!(empty.require_sorted_floats("t").is_ok());
+}
+
+#[test]
+fn require_sorted_floats_rejects_nan_in_middle() {
+    let err = [1.0, f64::NAN, 3.5]
+        .require_sorted_floats("thresholds")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'thresholds': element at index 1 is NaN"
+    );
+    // NaN is reported even if it comes after an ordering violation
+    let err = [2.0, 1.0, f64::NAN]
+        .require_sorted_floats("thresholds")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'thresholds': element at index 2 is NaN"
+    );
+}
+
+#[test]
+fn require_sorted_floats_rejects_descending() {
+    let err = [1.0f32, 3.0, 2.0]
+        .require_sorted_floats("thresholds")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'thresholds' must be in ascending order but element at index 2 breaks it"
+    );
+}
+
+#[test]
+fn finite_signum_returns_sign() {
+    assert_eq!((-3.0f64).require_finite_signum("x").unwrap(), -1);
+    assert_eq!(2.5f32.require_finite_signum("x").unwrap(), 1);
+    assert_eq!(0.0f64.require_finite_signum("x").unwrap(), 0);
+    assert_eq!((-0.0f64).require_finite_signum("x").unwrap(), 0);
+    assert_eq!(f64::MIN_POSITIVE.require_finite_signum("x").unwrap(), 1);
+}
+
+#[test]
+fn finite_signum_rejects_non_finite() {
+    let err = f64::NAN.require_finite_signum("x").unwrap_err();
+    assert_eq!(err.message(), "Parameter 'x' must be finite but was: NaN");
+    assert!(f32::NEG_INFINITY.require_finite_signum("x").is_err());
+}
+
+#[test]
+fn near_integer_accepts_values_close_to_integers() {
+    assert_eq!(3.0f64.require_near_integer("count", 1e-9).unwrap(), 3.0);
+    assert!(2.9999999f64.require_near_integer("count", 1e-6).is_ok());
+    assert!((-4.0000001f64).require_near_integer("count", 1e-6).is_ok());
+    assert!(7.00001f32.require_near_integer("count", 1e-3).is_ok());
+}
+
+#[test]
+fn near_integer_rejects_values_far_from_integers() {
+    let err = 3.4f64.require_near_integer("count", 1e-6).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'count' (3.4) must be approximately integral"
+    );
+    assert_eq!(err.parameter(), Some("count"));
+    assert!(0.5f64.require_near_integer("count", 0.1).is_err());
+    assert!(f64::NAN.require_near_integer("count", 1.0).is_err());
+    assert!(f64::INFINITY.require_near_integer("count", 1.0).is_err());
+}
+
+#[test]
+fn as_integer_checked_returns_rounded_value() {
+    assert_eq!(2.9999999f64.as_integer_checked("count", 1e-6).unwrap(), 3);
+    assert_eq!(
+        (-4.0000001f64).as_integer_checked("count", 1e-6).unwrap(),
+        -4
+    );
+    assert_eq!(12.0f32.as_integer_checked("count", 0.0).unwrap(), 12);
+
+    assert!(3.4f64.as_integer_checked("count", 1e-6).is_err());
+    let err = 1e20f64.as_integer_checked("count", 1e-6).unwrap_err();
+    assert!(err.message().contains("out of the range of i64"));
+    assert_eq!(err.parameter(), Some("count"));
+    assert!((i64::MAX as f64).as_integer_checked("count", 0.0).is_err());
+    assert_eq!(
+        (i64::MIN as f64).as_integer_checked("count", 0.0).unwrap(),
+        i64::MIN
+    );
+}
@@ -0,0 +1,198 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+use prism3_core::{
+    checked_div_validated,
+    IntegerArgument,
+    IntegerSliceArgument,
+    SignedIntegerArgument,
+};
+
+#[test]
+fn non_negative_as_unsigned_converts() {
+    assert_eq!(5i32.require_non_negative_as_unsigned("x").unwrap(), 5u32);
+    assert_eq!(0i8.require_non_negative_as_unsigned("x").unwrap(), 0u8);
+    assert_eq!(
+        i8::MAX.require_non_negative_as_unsigned("x").unwrap(),
+        127u8
+    );
+    assert_eq!(
+        i64::MAX.require_non_negative_as_unsigned("x").unwrap(),
+        i64::MAX as u64
+    );
+    assert_eq!(
+        1234isize.require_non_negative_as_unsigned("x").unwrap(),
+        1234usize
+    );
+    assert_eq!(7i128.require_non_negative_as_unsigned("x").unwrap(), 7u128);
+    assert_eq!(
+        300i16.require_non_negative_as_unsigned("x").unwrap(),
+        300u16
+    );
+}
+
+#[test]
+fn non_negative_as_unsigned_rejects_negative() {
+    let err = (-1i32).require_non_negative_as_unsigned("x").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'x' must be non-negative but was: -1"
+    );
+    assert!(i64::MIN.require_non_negative_as_unsigned("x").is_err());
+    assert!(i8::MIN.require_non_negative_as_unsigned("x").is_err());
+}
+
+#[test]
+fn valid_divisor_rejects_zero() {
+    assert_eq!(4i32.require_valid_divisor("divisor").unwrap(), 4);
+    assert_eq!((-1i64).require_valid_divisor("divisor").unwrap(), -1);
+    assert_eq!(3u8.require_valid_divisor("divisor").unwrap(), 3);
+
+    let err = 0usize.require_valid_divisor("divisor").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'divisor' cannot be used as a divisor because it is zero"
+    );
+}
+
+#[test]
+fn checked_div_validated_returns_quotient() {
+    assert_eq!(checked_div_validated(10, "total", 3, "count").unwrap(), 3);
+    assert_eq!(
+        checked_div_validated(-9i64, "total", 2, "count").unwrap(),
+        -4
+    );
+    assert_eq!(
+        checked_div_validated(255u8, "total", 5, "count").unwrap(),
+        51
+    );
+    assert_eq!(7i32.checked_div_validated("a", 2, "b").unwrap(), 3);
+}
+
+#[test]
+fn checked_div_validated_rejects_zero_divisor() {
+    let err = checked_div_validated(10, "total", 0, "count").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'count' cannot be used as a divisor because it is zero"
+    );
+    assert!(checked_div_validated(0u32, "total", 0, "count").is_err());
+}
+
+#[test]
+fn checked_div_validated_rejects_overflow() {
+    let err = checked_div_validated(i32::MIN, "total", -1, "count").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'total' (-2147483648) overflows when divided by parameter 'count' (-1)"
+    );
+    assert_eq!(err.parameter(), Some("total"));
+    assert!(checked_div_validated(i8::MIN, "total", -1, "count").is_err());
+    assert!(checked_div_validated(i128::MIN, "total", -1, "count").is_err());
+    assert_eq!(
+        checked_div_validated(i32::MIN + 1, "total", -1, "count").unwrap(),
+        i32::MAX
+    );
+}
+
+#[test]
+fn sum_in_range_returns_sum() {
+    assert_eq!(
+        i32::require_sum_in_range(40, 2, "total", 0, 100).unwrap(),
+        42
+    );
+    assert_eq!(0u8.require_sum_in_range(255, "total", 0, 255).unwrap(), 255);
+    assert_eq!((-5i64).require_sum_in_range(5, "total", 0, 0).unwrap(), 0);
+}
+
+#[test]
+fn sum_in_range_rejects_out_of_range_sum() {
+    let err = i32::require_sum_in_range(90, 20, "total", 0, 100).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'total' must be in range [0, 100] but was: 110"
+    );
+    assert!(i32::require_sum_in_range(-3, 2, "total", 0, 100).is_err());
+}
+
+#[test]
+fn sum_in_range_rejects_overflow() {
+    let err = i32::require_sum_in_range(i32::MAX, 1, "total", 0, i32::MAX).unwrap_err();
+    assert_eq!(err.message(), "Parameter 'total' overflows: 2147483647 + 1");
+    assert!(200u8
+        .require_sum_in_range(100, "total", 0, u8::MAX)
+        .is_err());
+    assert!(i8::MIN
+        .require_sum_in_range(-1, "total", i8::MIN, 0)
+        .is_err());
+}
+
+#[test]
+fn non_decreasing_with_gaps_returns_gaps() {
+    let timestamps = [100i64, 150, 150, 400];
+    assert_eq!(
+        timestamps
+            .require_non_decreasing_with_gaps("timestamps")
+            .unwrap(),
+        vec![50, 0, 250]
+    );
+    assert!([42i64]
+        .require_non_decreasing_with_gaps("timestamps")
+        .unwrap()
+        .is_empty());
+    let empty: [i64; 0] = [];
+    assert!(empty
+        .require_non_decreasing_with_gaps("timestamps")
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        [-5i32, 0, 5].require_non_decreasing_with_gaps("t").unwrap(),
+        vec![5, 5]
+    );
+}
+
+#[test]
+fn non_decreasing_with_gaps_rejects_decrease() {
+    let err = [100i64, 150, 120, 90]
+        .require_non_decreasing_with_gaps("timestamps")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'timestamps' must be non-decreasing but element at index 2 (120) \
+         is less than element at index 1 (150)"
+    );
+}
+
+#[test]
+fn non_decreasing_with_gaps_rejects_overflowing_gap() {
+    let err = [i8::MIN, i8::MAX]
+        .require_non_decreasing_with_gaps("t")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 't': gap between elements at indices 0 and 1 overflows"
+    );
+}
+
+#[test]
+fn non_negative_or_accepts_sentinel() {
+    assert_eq!((-1i32).require_non_negative_or("fd", -1).unwrap(), -1);
+    assert_eq!(0i32.require_non_negative_or("fd", -1).unwrap(), 0);
+    assert_eq!(42i64.require_non_negative_or("timeout", -1).unwrap(), 42);
+    assert_eq!(
+        i8::MIN.require_non_negative_or("x", i8::MIN).unwrap(),
+        i8::MIN
+    );
+
+    let err = (-2i32).require_non_negative_or("fd", -1).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'fd' must be non-negative or -1 but was: -2"
+    );
+    assert!((-1isize).require_non_negative_or("fd", -2).is_err());
+}
@@ -7,9 +7,15 @@
  *
  ******************************************************************************/
 use prism3_core::{
+    parse_named,
     require_equal,
+    require_equal_within,
     require_not_equal,
+    require_ordered,
+    require_sum_approx_equals,
+    require_sum_equals,
     NumericArgument,
+    Pair,
 };
 
 #[test]
@@ -90,6 +96,7 @@ fn free_functions_equal_and_not_equal() {
     assert!(require_equal("a", 100, "b", 100).is_ok());
     let err1 = require_equal("a", 100, "b", 200).unwrap_err();
     assert!(err1.message().contains("a"));
+    assert_eq!(err1.parameter(), Some("a"));
 
     assert!(require_not_equal("a", 1, "b", 2).is_ok());
     let err2 = require_not_equal("a", 2, "b", 2).unwrap_err();
@@ -122,3 +129,192 @@ fn big_integer_edges() {
     let umax = u128::MAX;
     assert!(umax.require_greater_equal("u", 0u128).is_ok());
 }
+
+#[test]
+fn require_ordered_returns_pair() {
+    assert_eq!(
+        require_ordered("min", 1, "max", 10).unwrap(),
+        Pair::new(1, 10)
+    );
+    assert_eq!(
+        require_ordered("min", 5, "max", 5).unwrap(),
+        Pair::new(5, 5)
+    );
+    assert_eq!(
+        require_ordered("lo", 0.5, "hi", 1.5).unwrap(),
+        Pair::new(0.5, 1.5)
+    );
+
+    let err = require_ordered("min", 10, "max", 1).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'min' (10) must be less than or equal to parameter 'max' (1)"
+    );
+    assert_eq!(err.parameter(), Some("min"));
+
+    // NaN bounds are never ordered
+    assert!(require_ordered("lo", f64::NAN, "hi", 1.0).is_err());
+}
+
+#[test]
+fn closed_range_checked_reports_inverted_bounds() {
+    assert_eq!(5i32.require_in_closed_range_checked("x", 1, 10).unwrap(), 5);
+    assert_eq!(1i32.require_in_closed_range_checked("x", 1, 1).unwrap(), 1);
+
+    let err = 5i32
+        .require_in_closed_range_checked("x", 10, 1)
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'x' has invalid bounds: minimum 10 is greater than maximum 1"
+    );
+    assert_eq!(err.parameter(), Some("x"));
+
+    // Valid bounds still produce the regular out of range error
+    let err = 50i32
+        .require_in_closed_range_checked("x", 1, 10)
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'x' must be in range [1, 10] but was: 50"
+    );
+
+    assert!(0.5f64
+        .require_in_closed_range_checked("x", 0.0, 1.0)
+        .is_ok());
+    assert!(0.5f64
+        .require_in_closed_range_checked("x", 1.0, 0.0)
+        .unwrap_err()
+        .message()
+        .contains("has invalid bounds"));
+}
+
+#[test]
+fn sum_equals_for_integers() {
+    assert!(require_sum_equals("shares", &[50, 30, 20], 100).is_ok());
+    assert!(require_sum_equals::<u32>("shares", &[], 0).is_ok());
+
+    let err = require_sum_equals("weights", &[50, 30, 15], 100).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Values in 'weights' must sum to 100 but summed to 95"
+    );
+}
+
+#[test]
+fn sum_equals_reports_overflow() {
+    let err = require_sum_equals("counts", &[i32::MAX, 1], 0).unwrap_err();
+    assert_eq!(err.message(), "Values in 'counts' overflow when summed");
+    assert!(require_sum_equals("counts", &[u8::MAX, 1], 0).is_err());
+    // The partial sums stay in range, so this does not overflow
+    assert!(require_sum_equals("counts", &[i32::MAX, -1, 1], i32::MAX).is_ok());
+
+    let err = require_sum_approx_equals("counts", &[i64::MAX, 1], 0, 1).unwrap_err();
+    assert_eq!(err.message(), "Values in 'counts' overflow when summed");
+    assert!(require_sum_approx_equals("counts", &[i32::MIN], i32::MAX, 1).is_err());
+}
+
+#[test]
+fn sum_approx_equals_for_floats() {
+    // 0.1 + 0.2 + 0.7 is not exactly 1.0 in binary floating point
+    assert!(require_sum_approx_equals("weights", &[0.1, 0.2, 0.7], 1.0, 1e-9).is_ok());
+    assert!(require_sum_approx_equals("weights", &[0.5f32, 0.5], 1.0, 0.0).is_ok());
+
+    let err = require_sum_approx_equals("weights", &[0.1, 0.2, 0.6], 1.0, 0.01).unwrap_err();
+    assert!(err
+        .message()
+        .starts_with("Values in 'weights' must sum to 1 (within 0.01) but summed to 0.9"));
+    assert!(require_sum_approx_equals("weights", &[0.5, f64::NAN], 1.0, 0.01).is_err());
+}
+
+#[test]
+fn within_open_interval_reports_violated_bound() {
+    assert_eq!(50.require_within_open_interval("v", 0, 100).unwrap(), 50);
+    assert!(0.5f64.require_within_open_interval("v", 0.0, 1.0).is_ok());
+
+    let too_low = 0.require_within_open_interval("v", 0, 100).unwrap_err();
+    assert_eq!(
+        too_low.message(),
+        "Parameter 'v' must be greater than 0 but was: 0"
+    );
+    let too_high = 150.require_within_open_interval("v", 0, 100).unwrap_err();
+    assert_eq!(
+        too_high.message(),
+        "Parameter 'v' must be less than 100 but was: 150"
+    );
+    assert_ne!(too_low.message(), too_high.message());
+
+    assert!(100.require_within_open_interval("v", 0, 100).is_err());
+    assert!(f64::NAN
+        .require_within_open_interval("v", 0.0, 1.0)
+        .is_err());
+}
+
+#[test]
+fn require_equal_within_tolerance() {
+    assert!(require_equal_within("expected", 100u32, "actual", 98, 2).is_ok());
+    assert!(require_equal_within("expected", 98u32, "actual", 100, 2).is_ok());
+    assert!(require_equal_within("a", -5i64, "b", -5, 0).is_ok());
+    assert!(require_equal_within("a", 1.0, "b", 1.05, 0.1).is_ok());
+}
+
+#[test]
+fn require_equal_within_outside_tolerance() {
+    let err = require_equal_within("expected", 100u32, "actual", 97, 2).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'expected' (100) must equal parameter 'actual' (97) within 2"
+    );
+    assert!(require_equal_within("a", 1.0, "b", 1.5, 0.1).is_err());
+    assert!(require_equal_within("a", f64::NAN, "b", 1.0, 0.1).is_err());
+    assert_eq!(err.parameter(), Some("expected"));
+}
+
+#[test]
+fn require_equal_within_overflowing_difference() {
+    let err = require_equal_within("a", i32::MIN, "b", i32::MAX, i32::MAX).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'a' (-2147483648) must equal parameter 'b' (2147483647) within 2147483647"
+    );
+    assert!(require_equal_within("a", i8::MAX, "b", i8::MIN, i8::MAX).is_err());
+    assert!(require_equal_within("a", u64::MAX, "b", 0, u64::MAX).is_ok());
+    assert!(require_equal_within("a", i64::MIN, "b", -1, i64::MAX).is_ok());
+}
+
+#[test]
+fn parse_named_success() {
+    assert_eq!(parse_named::<i32>("count", "42").unwrap(), 42);
+    assert_eq!(parse_named::<u8>("level", "255").unwrap(), 255);
+    assert_eq!(parse_named::<f64>("ratio", "0.5").unwrap(), 0.5);
+    assert!(parse_named::<bool>("debug", "true").unwrap());
+    assert_eq!(parse_named::<usize>("count", "7").unwrap(), 7);
+}
+
+#[test]
+fn parse_named_failure() {
+    let err = parse_named::<i32>("count", "x").unwrap_err();
+    assert_eq!(err.message(), "Parameter 'count' is not a valid i32: 'x'");
+    assert_eq!(err.parameter(), Some("count"));
+
+    let err = parse_named::<u8>("level", "256").unwrap_err();
+    assert_eq!(err.message(), "Parameter 'level' is not a valid u8: '256'");
+
+    let err = parse_named::<f64>("ratio", "half").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'ratio' is not a valid f64: 'half'"
+    );
+
+    let err = parse_named::<bool>("debug", "yes").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'debug' is not a valid bool: 'yes'"
+    );
+
+    let err = parse_named::<usize>("count", "-1").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'count' is not a valid usize: '-1'"
+    );
+}
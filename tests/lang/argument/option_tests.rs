@@ -7,6 +7,7 @@
  *
  ******************************************************************************/
 use prism3_core::{
+    from_option,
     require_null_or,
     ArgumentError,
     ArgumentResult,
@@ -957,3 +958,15 @@ fn test_all_functions_with_various_types_and_names() {
         let _ = some.require_non_null(name);
     }
 }
+
+#[test]
+fn from_option_matches_require_non_null() {
+    assert_eq!(from_option("port", Some(8080)).unwrap(), 8080);
+
+    let err = from_option::<u16>("port", None).unwrap_err();
+    assert_eq!(
+        err.message(),
+        None::<u16>.require_non_null("port").unwrap_err().message()
+    );
+    assert_eq!(err.parameter(), Some("port"));
+}
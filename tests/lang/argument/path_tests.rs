@@ -0,0 +1,64 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+use prism3_core::PathArgument;
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+#[test]
+fn require_non_empty_checks() {
+    assert!(Path::new("a.txt").require_non_empty("file").is_ok());
+    let err = Path::new("").require_non_empty("file").unwrap_err();
+    assert_eq!(err.message(), "Parameter 'file' cannot be an empty path");
+    assert!(PathBuf::new().require_non_empty("file").is_err());
+}
+
+#[test]
+fn require_extension_checks() {
+    assert!(Path::new("a/b.txt").require_extension("f", "txt").is_ok());
+    assert!(PathBuf::from("archive.tar.gz")
+        .require_extension("f", "gz")
+        .is_ok());
+
+    let err = Path::new("a/b.md")
+        .require_extension("f", "txt")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'f' must have extension 'txt' but was: 'a/b.md'"
+    );
+    assert!(Path::new("a/b").require_extension("f", "txt").is_err());
+    assert!(Path::new("a/b.TXT").require_extension("f", "txt").is_err());
+}
+
+#[test]
+fn require_relative_and_absolute_checks() {
+    let relative = Path::new("a/b.txt");
+    assert!(relative.require_is_relative("file").is_ok());
+    let err = relative.require_is_absolute("file").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'file' must be an absolute path but was: 'a/b.txt'"
+    );
+
+    let absolute = std::env::current_dir().unwrap();
+    assert!(absolute.require_is_absolute("dir").is_ok());
+    assert!(absolute.require_is_relative("dir").is_err());
+}
+
+#[test]
+fn path_checks_chain() {
+    let path = Path::new("config/app.toml");
+    let result = path
+        .require_non_empty("path")
+        .and_then(|p| p.require_is_relative("path"))
+        .and_then(|p| p.require_extension("path", "toml"));
+    assert_eq!(result.unwrap(), path);
+}
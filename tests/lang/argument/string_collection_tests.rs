@@ -0,0 +1,73 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+use prism3_core::StringCollectionArgument;
+
+#[test]
+fn prefix_free_accepts_clean_set() {
+    assert!(["get", "set", "delete"]
+        .require_prefix_free("commands")
+        .is_ok());
+    assert!(["/api/users", "/api/groups"]
+        .require_prefix_free("routes")
+        .is_ok());
+
+    let empty: Vec<String> = vec![];
+    assert!(empty.require_prefix_free("routes").is_ok());
+
+    let owned = vec!["alpha".to_string(), "beta".to_string()];
+    assert!(owned.require_prefix_free("commands").is_ok());
+}
+
+#[test]
+fn prefix_free_rejects_conflicting_set() {
+    let err = ["get", "set", "getall"]
+        .require_prefix_free("commands")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'commands': element at index 0 ('get') is a prefix of element at index 2 ('getall')"
+    );
+
+    // The prefix may come after the longer element
+    let routes = vec!["/api/users".to_string(), "/api".to_string()];
+    let err = routes.require_prefix_free("routes").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'routes': element at index 1 ('/api') is a prefix of element at index 0 ('/api/users')"
+    );
+
+    // Duplicates are prefixes of each other
+    assert!(["a", "b", "a"].require_prefix_free("commands").is_err());
+}
+
+#[test]
+fn all_trimmed_accepts_trimmed_elements() {
+    assert!(["rust", "web dev"].require_all_trimmed("tags").is_ok());
+    let empty: Vec<String> = Vec::new();
+    assert!(empty.require_all_trimmed("tags").is_ok());
+}
+
+#[test]
+fn all_trimmed_rejects_padded_element() {
+    let tags = vec!["rust".to_string(), "web ".to_string(), " cli".to_string()];
+    let err = tags.require_all_trimmed("tags").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'tags': element at index 1 has leading or trailing whitespace: 'web '"
+    );
+    assert!(["\tcli"].require_all_trimmed("tags").is_err());
+}
+
+#[test]
+fn trim_all_normalizes_elements() {
+    let tags = [" rust", "web ", "cli"];
+    let trimmed = tags.trim_all();
+    assert_eq!(trimmed, vec!["rust", "web", "cli"]);
+    assert!(trimmed.require_all_trimmed("tags").is_ok());
+}
@@ -6,8 +6,13 @@
  *    All rights reserved.
  *
  ******************************************************************************/
-use prism3_core::StringArgument;
+use prism3_core::{
+    require_enum,
+    NonBlankString,
+    StringArgument,
+};
 use regex::Regex;
+use std::str::FromStr;
 
 #[test]
 fn non_blank() {
@@ -379,3 +384,605 @@ fn test_length_in_range_both_boundaries() {
     let text4 = String::from("abcdefghijk");
     assert!(text4.require_length_in_range("text", 1, 5).is_err());
 }
+
+#[test]
+fn test_require_hex_string() {
+    assert!("deadBEEF".require_hex_string("hash", 8).is_ok());
+    assert!("0123456789abcdef".require_hex_string("hash", 16).is_ok());
+    assert!("".require_hex_string("hash", 0).is_ok());
+
+    // Wrong length
+    assert!("deadbeef".require_hex_string("hash", 6).is_err());
+
+    // Non-hex characters
+    let err = "xyz".require_hex_string("hash", 3).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'hash' must be a hexadecimal string of length 3 but was: 'xyz'"
+    );
+
+    let s = String::from("cafe");
+    assert_eq!(s.require_hex_string("hash", 4).unwrap(), "cafe");
+    assert!(s.require_hex_string("hash", 5).is_err());
+}
+
+#[test]
+fn test_require_uuid_format() {
+    let id = "550e8400-e29b-41d4-a716-446655440000";
+    assert_eq!(id.require_uuid_format("id").unwrap(), id);
+    assert!("550E8400-E29B-41D4-A716-446655440000"
+        .require_uuid_format("id")
+        .is_ok());
+
+    let err = "xyz".require_uuid_format("id").unwrap_err();
+    assert_eq!(err.message(), "Parameter 'id' is not a valid UUID: 'xyz'");
+
+    // Missing hyphens
+    assert!("550e8400e29b41d4a716446655440000"
+        .require_uuid_format("id")
+        .is_err());
+    // Misplaced hyphen
+    assert!("550e840-0e29b-41d4-a716-446655440000"
+        .require_uuid_format("id")
+        .is_err());
+    // Non-hex digit
+    assert!("550e8400-e29b-41d4-a716-44665544000g"
+        .require_uuid_format("id")
+        .is_err());
+    // Too long
+    assert!("550e8400-e29b-41d4-a716-4466554400000"
+        .require_uuid_format("id")
+        .is_err());
+
+    let s = String::from(id);
+    assert!(s.require_uuid_format("id").is_ok());
+    assert!(String::from("not-a-uuid")
+        .require_uuid_format("id")
+        .is_err());
+}
+
+#[test]
+fn test_require_ends_with_one_of() {
+    let allowed = [".png", ".jpg"];
+    assert!("photo.png"
+        .require_ends_with_one_of("filename", &allowed)
+        .is_ok());
+    assert!("photo.jpg"
+        .require_ends_with_one_of("filename", &allowed)
+        .is_ok());
+    assert!("PHOTO.PNG"
+        .require_ends_with_one_of("filename", &allowed)
+        .is_err());
+
+    let err = "doc.pdf"
+        .require_ends_with_one_of("filename", &allowed)
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'filename' must end with one of [\".png\", \".jpg\"] but was 'doc.pdf'"
+    );
+
+    // No suffix allowed means nothing passes
+    assert!("photo.png"
+        .require_ends_with_one_of("filename", &[])
+        .is_err());
+
+    let s = String::from("archive.tar.gz");
+    assert!(s.require_ends_with_one_of("filename", &[".gz"]).is_ok());
+    assert!(s.require_ends_with_one_of("filename", &[".zip"]).is_err());
+}
+
+#[test]
+fn test_require_ends_with_one_of_ignore_case() {
+    let allowed = [".png", ".JPG"];
+    assert!("PHOTO.PNG"
+        .require_ends_with_one_of_ignore_case("filename", &allowed)
+        .is_ok());
+    assert!("photo.jpg"
+        .require_ends_with_one_of_ignore_case("filename", &allowed)
+        .is_ok());
+    assert!("photo.Jpg"
+        .require_ends_with_one_of_ignore_case("filename", &allowed)
+        .is_ok());
+
+    let err = "doc.PDF"
+        .require_ends_with_one_of_ignore_case("filename", &allowed)
+        .unwrap_err();
+    assert!(err.message().contains("ignoring case"));
+    assert!(err.message().contains("'doc.PDF'"));
+
+    let s = String::from("Image.PNG");
+    assert!(s
+        .require_ends_with_one_of_ignore_case("filename", &allowed)
+        .is_ok());
+}
+
+#[test]
+fn test_require_no_control_chars() {
+    assert!("ok".require_no_control_chars("l").is_ok());
+    assert!("".require_no_control_chars("l").is_ok());
+    assert!("héllo wörld".require_no_control_chars("l").is_ok());
+    assert!("a\u{7}".require_no_control_chars("l").is_err());
+
+    let err = "abc\u{7}def".require_no_control_chars("label").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'label' contains control character U+0007 at byte 3"
+    );
+
+    // Byte position accounts for multi-byte characters before the control character
+    let err = "é\nx".require_no_control_chars("label").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'label' contains control character U+000A at byte 2"
+    );
+
+    let s = String::from("red\u{1b}[31m");
+    let err = s.require_no_control_chars("label").unwrap_err();
+    assert!(err.message().contains("U+001B at byte 3"));
+    assert!(String::from("plain").require_no_control_chars("l").is_ok());
+}
+
+#[test]
+fn test_require_ascii_length_be() {
+    assert!("AB12".require_ascii_length_be("code", 4).is_ok());
+    assert!("".require_ascii_length_be("code", 0).is_ok());
+
+    let err = "AB1".require_ascii_length_be("code", 4).unwrap_err();
+    assert_eq!(err.message(), "Parameter 'code' length must be 4 but was 3");
+
+    // "ÄB" is 3 bytes long, but must still be reported as non-ASCII
+    let err = "ÄB".require_ascii_length_be("code", 3).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'code' must be ASCII but was: 'ÄB'"
+    );
+    let err = "ÄB1".require_ascii_length_be("code", 4).unwrap_err();
+    assert!(err.message().contains("must be ASCII"));
+
+    let s = String::from("XYZ");
+    assert!(s.require_ascii_length_be("code", 3).is_ok());
+    assert!(s.require_ascii_length_be("code", 2).is_err());
+    assert!(String::from("日本")
+        .require_ascii_length_be("code", 6)
+        .is_err());
+}
+
+#[test]
+fn test_require_no_leading_zero() {
+    assert!("0".require_no_leading_zero("amount").is_ok());
+    assert!("123".require_no_leading_zero("amount").is_ok());
+    assert!("100".require_no_leading_zero("amount").is_ok());
+    assert!("".require_no_leading_zero("amount").is_ok());
+
+    let err = "007".require_no_leading_zero("amount").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'amount' must not have a leading zero: '007'"
+    );
+    assert!("00".require_no_leading_zero("amount").is_err());
+
+    assert!(String::from("0").require_no_leading_zero("amount").is_ok());
+    assert!(String::from("0123")
+        .require_no_leading_zero("amount")
+        .is_err());
+}
+
+#[test]
+fn test_require_chars_in() {
+    assert!("1010".require_chars_in("bits", "01").is_ok());
+    assert!("".require_chars_in("bits", "01").is_ok());
+
+    let err = "1020".require_chars_in("bits", "01").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'bits' contains character '2' at byte 2 that is not one of \"01\""
+    );
+
+    let s = String::from("héllo");
+    assert!(s.require_chars_in("word", "hélo").is_ok());
+    let err = s.require_chars_in("word", "helo").unwrap_err();
+    assert!(err.message().contains("character 'é' at byte 1"));
+}
+
+#[test]
+fn test_require_chars_matching() {
+    let is_slug_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    assert!("abc_1"
+        .require_chars_matching("slug", is_slug_char, "alphanumeric or underscore")
+        .is_ok());
+
+    let err = "abc-1"
+        .require_chars_matching("slug", is_slug_char, "alphanumeric or underscore")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'slug' contains character '-' at byte 3 that is not alphanumeric or underscore"
+    );
+
+    let s = String::from("über");
+    let err = s
+        .require_chars_matching("slug", is_slug_char, "alphanumeric or underscore")
+        .unwrap_err();
+    assert!(err.message().contains("character 'ü' at byte 0"));
+}
+
+#[test]
+fn test_require_trimmed_length_at_most() {
+    assert!("  hello  ".require_trimmed_length_at_most("x", 5).is_ok());
+    assert!("     ".require_trimmed_length_at_most("x", 0).is_ok());
+
+    let err = "  hello!  "
+        .require_trimmed_length_at_most("x", 5)
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'x' trimmed length must be at most 5 but was 6"
+    );
+
+    let s = String::from("\thi\n");
+    assert!(s.require_trimmed_length_at_most("x", 2).is_ok());
+    assert!(s.require_trimmed_length_at_most("x", 1).is_err());
+}
+
+#[test]
+fn test_require_trimmed_length_at_least() {
+    assert!(" abc ".require_trimmed_length_at_least("x", 3).is_ok());
+
+    let err = "  ab      "
+        .require_trimmed_length_at_least("x", 3)
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'x' trimmed length must be at least 3 but was 2"
+    );
+
+    let s = String::from("   ");
+    assert!(s.require_trimmed_length_at_least("x", 0).is_ok());
+    assert!(s.require_trimmed_length_at_least("x", 1).is_err());
+}
+
+#[derive(Debug, PartialEq)]
+enum Level {
+    Low,
+    High,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "low" => Ok(Level::Low),
+            "high" => Ok(Level::High),
+            _ => Err(format!("unknown level: {}", s)),
+        }
+    }
+}
+
+#[test]
+fn test_require_enum() {
+    let level: Level = require_enum("level", "high", &["low", "high"]).unwrap();
+    assert_eq!(level, Level::High);
+    assert_eq!(
+        require_enum::<Level>("level", "low", &["low", "high"]).unwrap(),
+        Level::Low
+    );
+
+    let err = require_enum::<Level>("level", "medium", &["low", "high"]).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'level' must be one of [\"low\", \"high\"] but was 'medium'"
+    );
+
+    // A listed variant that the type cannot parse reports the parse error
+    let err = require_enum::<Level>("level", "extreme", &["low", "extreme"]).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'level' could not be parsed from 'extreme': unknown level: extreme"
+    );
+}
+
+#[test]
+fn test_require_url_like() {
+    assert!("https://example.com/x".require_url_like("endpoint").is_ok());
+    assert!("http://localhost:8080".require_url_like("endpoint").is_ok());
+    assert!("svn+ssh://host/repo?rev=1#top"
+        .require_url_like("endpoint")
+        .is_ok());
+    assert!(String::from("ftp://user@host")
+        .require_url_like("endpoint")
+        .is_ok());
+
+    let err = "not a url".require_url_like("endpoint").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'endpoint' is not a valid URL (missing scheme): 'not a url'"
+    );
+    let err = "1http://example.com"
+        .require_url_like("endpoint")
+        .unwrap_err();
+    assert!(err.message().contains("(invalid scheme)"));
+    let err = "://example.com".require_url_like("endpoint").unwrap_err();
+    assert!(err.message().contains("(invalid scheme)"));
+    let err = "file:///etc/hosts"
+        .require_url_like("endpoint")
+        .unwrap_err();
+    assert!(err.message().contains("(empty authority)"));
+    let err = "https://example.com/a b"
+        .require_url_like("endpoint")
+        .unwrap_err();
+    assert!(err.message().contains("(contains whitespace)"));
+}
+
+#[test]
+fn require_parsed_in_range_checks() {
+    assert!("50".require_parsed_in_range::<i32>("pct", 0, 100).is_ok());
+    assert_eq!(
+        "0.5"
+            .to_string()
+            .require_parsed_in_range::<f64>("ratio", 0.0, 1.0)
+            .unwrap(),
+        0.5
+    );
+
+    let err = "150"
+        .require_parsed_in_range::<i32>("pct", 0, 100)
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'pct' must be in range [0, 100] but was: 150"
+    );
+
+    let err = "NaN"
+        .require_parsed_in_range::<f64>("ratio", 0.0, 1.0)
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'ratio' must be in range [0, 1] but was: NaN"
+    );
+
+    let err = "fifty"
+        .require_parsed_in_range::<i32>("pct", 0, 100)
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'pct' could not be parsed from 'fifty': invalid digit found in string"
+    );
+}
+
+#[test]
+fn require_consistent_case_checks() {
+    assert!("PATH".require_consistent_case("env").is_ok());
+    assert!("path".require_consistent_case("env").is_ok());
+    assert!("JAVA_HOME_2".require_consistent_case("env").is_ok());
+    assert!("123".require_consistent_case("env").is_ok());
+    assert!("".to_string().require_consistent_case("env").is_ok());
+
+    let err = "Path".require_consistent_case("env").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'env' must be all uppercase or all lowercase: 'Path'"
+    );
+    assert!("java_Home"
+        .to_string()
+        .require_consistent_case("env")
+        .is_err());
+}
+
+#[test]
+fn non_blank_string_rejects_blank() {
+    for blank in ["", "   ", "\t\n"] {
+        let err = NonBlankString::new(blank).unwrap_err();
+        assert_eq!(
+            err.message(),
+            "String cannot be empty or contain only whitespace characters"
+        );
+    }
+}
+
+#[test]
+fn non_blank_string_derefs_to_str() {
+    let name = NonBlankString::new("alice".to_string()).unwrap();
+    assert_eq!(name.len(), 5);
+    assert!(name.starts_with("al"));
+    assert_eq!(&*name, "alice");
+    assert_eq!(name.as_str(), "alice");
+    assert_eq!(format!("[{:>7}]", name), "[  alice]");
+
+    // Downstream validators accept it as a string slice
+    assert!(name.require_length_at_most("name", 10).is_ok());
+    assert_eq!(name.into_inner(), "alice");
+}
+
+#[test]
+fn require_balanced_accepts_balanced() {
+    assert!("f(g(x), y)".require_balanced("expr", '(', ')').is_ok());
+    assert!("no delimiters".require_balanced("expr", '(', ')').is_ok());
+    assert!("{{ name }}"
+        .to_string()
+        .require_balanced("template", '{', '}')
+        .is_ok());
+}
+
+#[test]
+fn require_balanced_rejects_extra_close() {
+    let err = "f(x))".require_balanced("expr", '(', ')').unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'expr' has unmatched ')' at byte 4: 'f(x))'"
+    );
+    assert!(")(".require_balanced("expr", '(', ')').is_err());
+}
+
+#[test]
+fn require_balanced_rejects_unclosed() {
+    let err = "a(b(c)".require_balanced("expr", '(', ')').unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'expr' has unclosed '(' at byte 1: 'a(b(c)'"
+    );
+    assert!("{{ name }".require_balanced("template", '{', '}').is_err());
+}
+
+#[test]
+fn require_balanced_identical_delimiters() {
+    assert!("\"a\"".require_balanced("quoted", '"', '"').is_ok());
+    assert!("\"a\" and \"b\""
+        .require_balanced("quoted", '"', '"')
+        .is_ok());
+    let err = "\"a\" \"b"
+        .require_balanced("quoted", '"', '"')
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'quoted' has unclosed '\"' at byte 4: '\"a\" \"b'"
+    );
+}
+
+#[test]
+fn require_single_line_checks() {
+    assert!("title".require_single_line("t").is_ok());
+    assert!("".to_string().require_single_line("t").is_ok());
+    assert!("tabs\tare fine".require_single_line("t").is_ok());
+
+    let err = "a\nb".require_single_line("t").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 't' must be a single line but contains a line break at byte 1: \"a\\nb\""
+    );
+    let err = "abc\r\n".to_string().require_single_line("t").unwrap_err();
+    assert!(err.message().contains("at byte 3"));
+}
+
+#[test]
+fn require_non_empty_allows_whitespace() {
+    assert!("   ".require_non_empty("separator").is_ok());
+    assert!("a".to_string().require_non_empty("separator").is_ok());
+
+    let err = "".require_non_empty("separator").unwrap_err();
+    assert_eq!(err.message(), "Parameter 'separator' cannot be empty");
+    assert!(String::new().require_non_empty("separator").is_err());
+
+    // Unlike require_non_blank
+    assert!("   ".require_non_blank("separator").is_err());
+}
+
+#[test]
+fn require_email_checks() {
+    assert!("a@b.com".require_email("e").is_ok());
+    assert!("john.doe+tag@mail.example.co.uk"
+        .to_string()
+        .require_email("e")
+        .is_ok());
+
+    let err = "a@b".require_email("e").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'e' is not a valid email address: 'a@b'"
+    );
+    for invalid in [
+        "",
+        "john",
+        "@example.com",
+        "john@",
+        "john@-example.com",
+        "jo hn@example.com",
+    ] {
+        assert!(invalid.require_email("e").is_err(), "{}", invalid);
+    }
+}
+
+#[test]
+fn trailing_newline_checks() {
+    assert!("line\n".require_trailing_newline("content").is_ok());
+    assert!("a\r\nb\r\n".require_trailing_newline("content").is_ok());
+    assert!("line\n"
+        .to_string()
+        .require_trailing_newline("content")
+        .is_ok());
+    let err = "line".require_trailing_newline("content").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'content' must end with a newline but does not"
+    );
+    assert!("".require_trailing_newline("content").is_err());
+
+    assert!("line".require_no_trailing_newline("content").is_ok());
+    assert!("".require_no_trailing_newline("content").is_ok());
+    assert!("a\nb"
+        .to_string()
+        .require_no_trailing_newline("content")
+        .is_ok());
+    let err = "line\n".require_no_trailing_newline("content").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'content' must not end with a newline but does"
+    );
+}
+
+#[test]
+fn ascii_hex_checks() {
+    assert!("DeadBeef".require_ascii_hex("key").is_ok());
+    assert!("".require_ascii_hex("key").is_ok());
+    assert!("0123456789abcdef"
+        .to_string()
+        .require_ascii_hex("key")
+        .is_ok());
+
+    let err = "deadbeez".require_ascii_hex("key").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'key' contains non-hexadecimal character 'z' at byte 7: 'deadbeez'"
+    );
+    let err = "abc".require_ascii_hex("key").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'key' must have an even number of hexadecimal digits but has 3: 'abc'"
+    );
+    assert!("0x1f".require_ascii_hex("key").is_err());
+}
+
+#[test]
+fn decode_hex_returns_bytes() {
+    assert_eq!("deadbeef".decode_hex("key").unwrap().len(), 4);
+    assert_eq!(
+        "DEADbeef".to_string().decode_hex("key").unwrap(),
+        vec![0xde, 0xad, 0xbe, 0xef]
+    );
+    assert_eq!("00ff10".decode_hex("key").unwrap(), vec![0x00, 0xff, 0x10]);
+    assert!("".decode_hex("key").unwrap().is_empty());
+    assert!("xyz0".decode_hex("key").is_err());
+    assert!("abc".decode_hex("key").is_err());
+}
+
+#[test]
+fn require_strip_prefix_returns_remainder() {
+    assert_eq!(
+        "Bearer abc"
+            .require_strip_prefix("auth", "Bearer ")
+            .unwrap(),
+        "abc"
+    );
+    let header = String::from("Bearer token-123");
+    assert_eq!(
+        header.require_strip_prefix("auth", "Bearer ").unwrap(),
+        "token-123"
+    );
+    assert_eq!(
+        "Bearer ".require_strip_prefix("auth", "Bearer ").unwrap(),
+        ""
+    );
+    assert_eq!("abc".require_strip_prefix("auth", "").unwrap(), "abc");
+
+    let err = "Basic abc"
+        .require_strip_prefix("auth", "Bearer ")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'auth' must start with 'Bearer ' but was: 'Basic abc'"
+    );
+    assert!("bearer abc"
+        .require_strip_prefix("auth", "Bearer ")
+        .is_err());
+}
@@ -0,0 +1,68 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+use chrono::NaiveDate;
+use prism3_core::lang::argument::require_date_in_range;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn date_in_range_accepts_inclusive_bounds() {
+    let min = date(1900, 1, 1);
+    let max = date(2025, 12, 31);
+    assert_eq!(
+        require_date_in_range("birthday", min, min, max).unwrap(),
+        min
+    );
+    assert_eq!(
+        require_date_in_range("birthday", max, min, max).unwrap(),
+        max
+    );
+    assert_eq!(
+        require_date_in_range("birthday", date(1990, 6, 15), min, max).unwrap(),
+        date(1990, 6, 15)
+    );
+    // A single-day range is valid
+    assert_eq!(require_date_in_range("day", min, min, min).unwrap(), min);
+}
+
+#[test]
+fn date_in_range_rejects_dates_outside_bounds() {
+    let min = date(1900, 1, 1);
+    let max = date(2025, 12, 31);
+
+    let err = require_date_in_range("birthday", date(1899, 12, 31), min, max).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'birthday' must be in date range [1900-01-01, 2025-12-31] but was: 1899-12-31"
+    );
+
+    let err = require_date_in_range("birthday", date(2026, 1, 1), min, max).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'birthday' must be in date range [1900-01-01, 2025-12-31] but was: 2026-01-01"
+    );
+}
+
+#[test]
+fn date_in_range_rejects_inverted_range() {
+    let err = require_date_in_range(
+        "effective_date",
+        date(2020, 1, 1),
+        date(2021, 1, 1),
+        date(2020, 12, 31),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'effective_date' has an invalid date range: minimum 2021-01-01 is after maximum 2020-12-31"
+    );
+    assert_eq!(err.parameter(), Some("effective_date"));
+}
@@ -0,0 +1,139 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+use prism3_core::lang::argument::TypedArgumentError;
+use prism3_core::{
+    ArgumentError,
+    CollectionArgument,
+    NumericArgument,
+    StringArgument,
+};
+
+#[test]
+fn typed_error_display_matches_validation_messages() {
+    let cases = [
+        (
+            TypedArgumentError::Blank {
+                parameter: "name".to_string(),
+            },
+            "  ".require_non_blank("name").unwrap_err(),
+        ),
+        (
+            TypedArgumentError::EmptyCollection {
+                parameter: "items".to_string(),
+            },
+            Vec::<i32>::new().require_non_empty("items").unwrap_err(),
+        ),
+        (
+            TypedArgumentError::NotPositive {
+                parameter: "count".to_string(),
+                actual: "0".to_string(),
+            },
+            0.require_positive("count").unwrap_err(),
+        ),
+        (
+            TypedArgumentError::Negative {
+                parameter: "offset".to_string(),
+                actual: "-1".to_string(),
+            },
+            (-1).require_non_negative("offset").unwrap_err(),
+        ),
+        (
+            TypedArgumentError::OutOfRange {
+                parameter: "age".to_string(),
+                min: "0".to_string(),
+                max: "150".to_string(),
+                actual: "200".to_string(),
+            },
+            200.require_in_closed_range("age", 0, 150).unwrap_err(),
+        ),
+        (
+            TypedArgumentError::InvalidBounds {
+                parameter: "age".to_string(),
+                min: "150".to_string(),
+                max: "0".to_string(),
+            },
+            20.require_in_closed_range_checked("age", 150, 0)
+                .unwrap_err(),
+        ),
+    ];
+    for (typed, error) in cases {
+        assert_eq!(typed.to_string(), error.message());
+
+        // Converting yields the same error as the validation method, parameter included
+        let converted: ArgumentError = typed.into();
+        assert_eq!(converted.message(), error.message());
+        assert_eq!(converted.parameter(), error.parameter());
+    }
+}
+
+#[test]
+fn typed_error_converts_to_argument_error() {
+    let typed = TypedArgumentError::OutOfRange {
+        parameter: "age".to_string(),
+        min: "0".to_string(),
+        max: "150".to_string(),
+        actual: "200".to_string(),
+    };
+    let error: ArgumentError = typed.into();
+    assert_eq!(
+        error.message(),
+        "Parameter 'age' must be in range [0, 150] but was: 200"
+    );
+    assert_eq!(error.parameter(), Some("age"));
+
+    let error: ArgumentError = TypedArgumentError::Other {
+        parameter: None,
+        message: "custom failure".to_string(),
+    }
+    .into();
+    assert_eq!(error.message(), "custom failure");
+    assert_eq!(error.parameter(), None);
+}
+
+#[test]
+fn argument_error_converts_to_other_variant() {
+    let error = ArgumentError::for_param("port", "must be a privileged port");
+    let typed: TypedArgumentError = error.clone().into();
+    assert_eq!(
+        typed,
+        TypedArgumentError::Other {
+            parameter: Some("port".to_string()),
+            message: "Parameter 'port' must be a privileged port".to_string(),
+        }
+    );
+
+    // Round trip preserves the message and the parameter name
+    let back: ArgumentError = typed.into();
+    assert_eq!(back, error);
+    assert_eq!(back.parameter(), Some("port"));
+
+    let typed: TypedArgumentError = ArgumentError::new("custom failure").into();
+    assert_eq!(
+        typed,
+        TypedArgumentError::Other {
+            parameter: None,
+            message: "custom failure".to_string(),
+        }
+    );
+}
+
+#[test]
+fn typed_error_is_matchable() {
+    let typed = TypedArgumentError::NotPositive {
+        parameter: "count".to_string(),
+        actual: "0".to_string(),
+    };
+    match typed {
+        TypedArgumentError::NotPositive { parameter, actual } => {
+            assert_eq!(parameter, "count");
+            assert_eq!(actual, "0");
+        }
+        other => panic!("unexpected variant: {:?}", other),
+    }
+}
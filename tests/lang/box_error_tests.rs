@@ -0,0 +1,42 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # BoxError Unit Tests
+//!
+//! Tests for boxed error helpers.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use prism3_core::{
+    require_arg,
+    ArgumentError,
+    BoxResult,
+};
+
+fn set_threads(count: usize) -> BoxResult<usize> {
+    require_arg(count > 0, "Thread count must be positive")?;
+    Ok(count)
+}
+
+/// Test require_arg with a satisfied condition
+#[test]
+fn test_require_arg_ok() {
+    assert!(require_arg(true, "unused").is_ok());
+    assert_eq!(set_threads(4).unwrap(), 4);
+}
+
+/// Test that require_arg returns a boxed ArgumentError
+#[test]
+fn test_require_arg_err() {
+    let err = set_threads(0).unwrap_err();
+    assert_eq!(err.to_string(), "Thread count must be positive");
+    let err = err.downcast::<ArgumentError>().unwrap();
+    assert_eq!(err.message(), "Thread count must be positive");
+}
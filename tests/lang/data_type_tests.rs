@@ -14,7 +14,13 @@
 //!
 //! Haixing Hu
 
-use prism3_core::DataType;
+use prism3_core::{
+    impl_data_type_of,
+    require_byte_len_for,
+    DataType,
+    DataTypeOf,
+};
+use std::collections::HashSet;
 
 /// Test DataType::as_str method for all data types
 #[test]
@@ -156,3 +162,530 @@ fn test_data_type_debug() {
         assert!(!debug_str.is_empty(), "Debug output should not be empty");
     }
 }
+
+/// Test DataType::display_name for several data types
+#[test]
+fn test_data_type_display_name() {
+    assert_eq!(DataType::Int32.display_name(), "32-bit integer");
+    assert_eq!(DataType::UInt8.display_name(), "8-bit unsigned integer");
+    assert_eq!(
+        DataType::Float64.display_name(),
+        "64-bit floating-point number"
+    );
+    assert_eq!(DataType::String.display_name(), "UTF-8 string");
+    assert_eq!(DataType::Date.display_name(), "date");
+    assert_eq!(
+        DataType::BigDecimal.display_name(),
+        "arbitrary-precision decimal"
+    );
+}
+
+/// Test that DataType::display_name labels are non-empty and distinct
+#[test]
+fn test_data_type_display_name_distinct() {
+    let names: HashSet<&str> = DataType::ALL.iter().map(|t| t.display_name()).collect();
+    assert_eq!(names.len(), DataType::ALL.len());
+    assert!(names.iter().all(|name| !name.is_empty()));
+}
+
+/// Test DataType::json_schema_type for numeric types
+#[test]
+fn test_data_type_json_schema_type_numeric() {
+    assert_eq!(DataType::Int8.json_schema_type(), ("integer", Some("int8")));
+    assert_eq!(
+        DataType::Int32.json_schema_type(),
+        ("integer", Some("int32"))
+    );
+    assert_eq!(
+        DataType::Int64.json_schema_type(),
+        ("integer", Some("int64"))
+    );
+    assert_eq!(
+        DataType::UInt16.json_schema_type(),
+        ("integer", Some("uint16"))
+    );
+    assert_eq!(
+        DataType::Float32.json_schema_type(),
+        ("number", Some("float"))
+    );
+    assert_eq!(
+        DataType::Float64.json_schema_type(),
+        ("number", Some("double"))
+    );
+    assert_eq!(DataType::BigInteger.json_schema_type(), ("string", None));
+    assert_eq!(DataType::BigDecimal.json_schema_type(), ("string", None));
+}
+
+/// Test DataType::json_schema_type for temporal types
+#[test]
+fn test_data_type_json_schema_type_temporal() {
+    assert_eq!(DataType::Date.json_schema_type(), ("string", Some("date")));
+    assert_eq!(DataType::Time.json_schema_type(), ("string", Some("time")));
+    assert_eq!(
+        DataType::DateTime.json_schema_type(),
+        ("string", Some("date-time"))
+    );
+    assert_eq!(
+        DataType::Instant.json_schema_type(),
+        ("string", Some("date-time"))
+    );
+}
+
+/// Test DataType::json_schema_type for textual and boolean types
+#[test]
+fn test_data_type_json_schema_type_textual() {
+    assert_eq!(DataType::String.json_schema_type(), ("string", None));
+    assert_eq!(DataType::Char.json_schema_type(), ("string", None));
+    assert_eq!(DataType::Bool.json_schema_type(), ("boolean", None));
+}
+
+/// Test DataType::rust_type_name for all data types
+#[test]
+fn test_data_type_rust_type_name_all_types() {
+    let expected = [
+        (DataType::Bool, "bool"),
+        (DataType::Char, "char"),
+        (DataType::Int8, "i8"),
+        (DataType::Int16, "i16"),
+        (DataType::Int32, "i32"),
+        (DataType::Int64, "i64"),
+        (DataType::Int128, "i128"),
+        (DataType::UInt8, "u8"),
+        (DataType::UInt16, "u16"),
+        (DataType::UInt32, "u32"),
+        (DataType::UInt64, "u64"),
+        (DataType::UInt128, "u128"),
+        (DataType::Float32, "f32"),
+        (DataType::Float64, "f64"),
+        (DataType::String, "String"),
+        (DataType::Date, "chrono::NaiveDate"),
+        (DataType::Time, "chrono::NaiveTime"),
+        (DataType::DateTime, "chrono::NaiveDateTime"),
+        (DataType::Instant, "chrono::DateTime<chrono::Utc>"),
+        (DataType::BigInteger, "num_bigint::BigInt"),
+        (DataType::BigDecimal, "bigdecimal::BigDecimal"),
+    ];
+
+    for (data_type, name) in expected {
+        assert_eq!(data_type.rust_type_name(), name);
+        assert!(!data_type.rust_type_name().is_empty());
+    }
+}
+
+/// Test that DataType::rust_type_name refers to types that exist
+#[test]
+fn test_data_type_rust_type_name_compiles() {
+    // Each generated name must resolve to a real type with the same DataType
+    let _: chrono::NaiveDate = chrono::NaiveDate::default();
+    let _: chrono::NaiveTime = chrono::NaiveTime::default();
+    let _: chrono::NaiveDateTime = chrono::NaiveDateTime::default();
+    let _: chrono::DateTime<chrono::Utc> = chrono::DateTime::<chrono::Utc>::default();
+    let _: num_bigint::BigInt = num_bigint::BigInt::default();
+    let _: bigdecimal::BigDecimal = bigdecimal::BigDecimal::default();
+}
+
+/// Test DataType::can_widen_to against the full widening truth table
+#[test]
+fn test_data_type_can_widen_to_truth_table() {
+    use DataType::*;
+
+    let all = [
+        Bool, Char, Int8, Int16, Int32, Int64, Int128, UInt8, UInt16, UInt32, UInt64, UInt128,
+        Float32, Float64, String, Date, Time, DateTime, Instant, BigInteger, BigDecimal,
+    ];
+    // Expected widening targets of each type, excluding the type itself
+    let table: [(DataType, &[DataType]); 21] = [
+        (Bool, &[]),
+        (Char, &[]),
+        (
+            Int8,
+            &[
+                Int16, Int32, Int64, Int128, Float32, Float64, BigInteger, BigDecimal,
+            ],
+        ),
+        (
+            Int16,
+            &[
+                Int32, Int64, Int128, Float32, Float64, BigInteger, BigDecimal,
+            ],
+        ),
+        (Int32, &[Int64, Int128, Float64, BigInteger, BigDecimal]),
+        (Int64, &[Int128, BigInteger, BigDecimal]),
+        (Int128, &[BigInteger, BigDecimal]),
+        (
+            UInt8,
+            &[
+                UInt16, UInt32, UInt64, UInt128, Int16, Int32, Int64, Int128, Float32, Float64,
+                BigInteger, BigDecimal,
+            ],
+        ),
+        (
+            UInt16,
+            &[
+                UInt32, UInt64, UInt128, Int32, Int64, Int128, Float32, Float64, BigInteger,
+                BigDecimal,
+            ],
+        ),
+        (
+            UInt32,
+            &[
+                UInt64, UInt128, Int64, Int128, Float64, BigInteger, BigDecimal,
+            ],
+        ),
+        (UInt64, &[UInt128, Int128, BigInteger, BigDecimal]),
+        (UInt128, &[BigInteger, BigDecimal]),
+        (Float32, &[Float64]),
+        (Float64, &[]),
+        (String, &[]),
+        (Date, &[]),
+        (Time, &[]),
+        (DateTime, &[]),
+        (Instant, &[]),
+        (BigInteger, &[BigDecimal]),
+        (BigDecimal, &[]),
+    ];
+
+    for (source, targets) in table {
+        for target in all {
+            let expected = source == target || targets.contains(&target);
+            assert_eq!(
+                source.can_widen_to(target),
+                expected,
+                "{} -> {}",
+                source,
+                target
+            );
+        }
+    }
+}
+
+/// Test that DataType::can_widen_to never allows narrowing
+#[test]
+fn test_data_type_can_widen_to_is_antisymmetric() {
+    assert!(DataType::Int8.can_widen_to(DataType::Int64));
+    assert!(!DataType::Int64.can_widen_to(DataType::Int8));
+    assert!(DataType::Float32.can_widen_to(DataType::Float64));
+    assert!(!DataType::Float64.can_widen_to(DataType::Float32));
+    assert!(!DataType::Int8.can_widen_to(DataType::UInt8));
+    assert!(!DataType::UInt8.can_widen_to(DataType::Int8));
+}
+
+/// Test DataType::validate_json with matching values
+#[test]
+fn test_data_type_validate_json_matching() {
+    use serde_json::json;
+
+    assert!(DataType::Bool.validate_json(&json!(true)).is_ok());
+    assert!(DataType::Char.validate_json(&json!("x")).is_ok());
+    assert!(DataType::Int8.validate_json(&json!(-128)).is_ok());
+    assert!(DataType::Int32.validate_json(&json!(42)).is_ok());
+    assert!(DataType::Int64.validate_json(&json!(i64::MIN)).is_ok());
+    assert!(DataType::Int128.validate_json(&json!(u64::MAX)).is_ok());
+    assert!(DataType::UInt8.validate_json(&json!(255)).is_ok());
+    assert!(DataType::UInt64.validate_json(&json!(u64::MAX)).is_ok());
+    assert!(DataType::Float32.validate_json(&json!(1.5)).is_ok());
+    assert!(DataType::Float64.validate_json(&json!(1)).is_ok());
+    assert!(DataType::Float64.validate_json(&json!(1e300)).is_ok());
+    assert!(DataType::String.validate_json(&json!("hello")).is_ok());
+    assert!(DataType::Date.validate_json(&json!("2025-01-31")).is_ok());
+    assert!(DataType::Time.validate_json(&json!("12:30:00")).is_ok());
+    assert!(DataType::DateTime
+        .validate_json(&json!("2025-01-31T12:30:00"))
+        .is_ok());
+    assert!(DataType::Instant
+        .validate_json(&json!("2025-01-31T12:30:00Z"))
+        .is_ok());
+    assert!(DataType::BigInteger.validate_json(&json!(123)).is_ok());
+    assert!(DataType::BigInteger
+        .validate_json(&json!("123456789012345678901234567890"))
+        .is_ok());
+    assert!(DataType::BigDecimal.validate_json(&json!(1.25)).is_ok());
+    assert!(DataType::BigDecimal
+        .validate_json(&json!("3.14159265358979323846"))
+        .is_ok());
+}
+
+/// Test DataType::validate_json with mismatching values
+#[test]
+fn test_data_type_validate_json_mismatching() {
+    use serde_json::json;
+
+    let err = DataType::Int32.validate_json(&json!("abc")).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "JSON string \"abc\" does not match data type 'int32'"
+    );
+    let err = DataType::Int8.validate_json(&json!(300)).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "JSON number 300 does not match data type 'int8'"
+    );
+
+    assert!(DataType::Bool.validate_json(&json!(1)).is_err());
+    assert!(DataType::Char.validate_json(&json!("xy")).is_err());
+    assert!(DataType::Int32.validate_json(&json!(1.5)).is_err());
+    assert!(DataType::Int64.validate_json(&json!(u64::MAX)).is_err());
+    assert!(DataType::UInt8.validate_json(&json!(-1)).is_err());
+    assert!(DataType::UInt32.validate_json(&json!(u64::MAX)).is_err());
+    assert!(DataType::Float32.validate_json(&json!(1e300)).is_err());
+    assert!(DataType::Float64.validate_json(&json!("1.0")).is_err());
+    assert!(DataType::String.validate_json(&json!(null)).is_err());
+    assert!(DataType::String.validate_json(&json!(["a"])).is_err());
+    assert!(DataType::Date.validate_json(&json!("2025-02-30")).is_err());
+    assert!(DataType::Time.validate_json(&json!(12)).is_err());
+    assert!(DataType::Instant
+        .validate_json(&json!("2025-01-31T12:30:00"))
+        .is_err());
+    assert!(DataType::BigInteger.validate_json(&json!("12.5")).is_err());
+    assert!(DataType::BigDecimal.validate_json(&json!({})).is_err());
+}
+
+/// Test DataType::validate_json_named records the parameter name
+#[test]
+fn test_data_type_validate_json_named() {
+    use serde_json::json;
+
+    assert!(DataType::Int32
+        .validate_json_named("port", &json!(8080))
+        .is_ok());
+    let err = DataType::Int32
+        .validate_json_named("port", &json!("abc"))
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'port' (JSON string \"abc\") does not match data type 'int32'"
+    );
+    assert_eq!(err.parameter(), Some("port"));
+}
+
+/// Position of a data type in declaration order.
+///
+/// The match is exhaustive, so adding a variant without updating this test
+/// (and `DataType::ALL`) fails to compile.
+fn variant_index(data_type: DataType) -> usize {
+    match data_type {
+        DataType::Bool => 0,
+        DataType::Char => 1,
+        DataType::Int8 => 2,
+        DataType::Int16 => 3,
+        DataType::Int32 => 4,
+        DataType::Int64 => 5,
+        DataType::Int128 => 6,
+        DataType::UInt8 => 7,
+        DataType::UInt16 => 8,
+        DataType::UInt32 => 9,
+        DataType::UInt64 => 10,
+        DataType::UInt128 => 11,
+        DataType::Float32 => 12,
+        DataType::Float64 => 13,
+        DataType::String => 14,
+        DataType::Date => 15,
+        DataType::Time => 16,
+        DataType::DateTime => 17,
+        DataType::Instant => 18,
+        DataType::BigInteger => 19,
+        DataType::BigDecimal => 20,
+    }
+}
+
+/// Test that DataType::ALL lists every variant exactly once
+#[test]
+fn test_data_type_all_covers_every_variant() {
+    assert_eq!(DataType::ALL.len(), 21);
+    for (i, data_type) in DataType::ALL.iter().enumerate() {
+        assert_eq!(variant_index(*data_type), i);
+    }
+}
+
+/// Test that every DataType round-trips through as_str and from_str
+#[test]
+fn test_data_type_from_str_round_trip() {
+    for data_type in DataType::ALL {
+        assert_eq!(data_type.as_str().parse::<DataType>().unwrap(), *data_type);
+    }
+    let err = "integer".parse::<DataType>().unwrap_err();
+    assert_eq!(err.message(), "Unknown data type: 'integer'");
+    assert!("Int32".parse::<DataType>().is_err());
+    assert!("".parse::<DataType>().is_err());
+}
+
+/// Test DataType::NUMERIC and DataType::TEMPORAL groups
+#[test]
+fn test_data_type_groups() {
+    assert_eq!(DataType::NUMERIC.len(), 14);
+    assert!(DataType::NUMERIC.contains(&DataType::Int32));
+    assert!(DataType::NUMERIC.contains(&DataType::BigDecimal));
+    assert!(!DataType::NUMERIC.contains(&DataType::String));
+
+    assert_eq!(
+        DataType::TEMPORAL,
+        &[
+            DataType::Date,
+            DataType::Time,
+            DataType::DateTime,
+            DataType::Instant
+        ]
+    );
+
+    // Groups are disjoint subsets of ALL
+    for data_type in DataType::NUMERIC.iter().chain(DataType::TEMPORAL) {
+        assert!(DataType::ALL.contains(data_type));
+    }
+    assert!(DataType::NUMERIC
+        .iter()
+        .all(|data_type| !DataType::TEMPORAL.contains(data_type)));
+}
+
+/// Test that DataType::from_rust_type_name inverts rust_type_name
+#[test]
+fn test_data_type_from_rust_type_name_round_trip() {
+    for data_type in DataType::ALL {
+        assert_eq!(
+            DataType::from_rust_type_name(data_type.rust_type_name()),
+            Some(*data_type)
+        );
+    }
+}
+
+/// Test DataType::from_rust_type_name aliases and unknown names
+#[test]
+fn test_data_type_from_rust_type_name_aliases() {
+    assert_eq!(
+        DataType::from_rust_type_name("isize"),
+        Some(DataType::Int64)
+    );
+    assert_eq!(
+        DataType::from_rust_type_name("usize"),
+        Some(DataType::UInt64)
+    );
+    assert_eq!(
+        DataType::from_rust_type_name("&str"),
+        Some(DataType::String)
+    );
+    assert_eq!(DataType::from_rust_type_name("str"), Some(DataType::String));
+    assert_eq!(
+        DataType::from_rust_type_name("NaiveDate"),
+        Some(DataType::Date)
+    );
+    assert_eq!(
+        DataType::from_rust_type_name("DateTime<Utc>"),
+        Some(DataType::Instant)
+    );
+    assert_eq!(
+        DataType::from_rust_type_name("BigInt"),
+        Some(DataType::BigInteger)
+    );
+
+    assert_eq!(DataType::from_rust_type_name("int32"), None);
+    assert_eq!(DataType::from_rust_type_name("Vec<u8>"), None);
+    assert_eq!(DataType::from_rust_type_name(""), None);
+}
+
+/// Test DataType::size_in_bytes for fixed and variable width types
+#[test]
+fn test_data_type_size_in_bytes() {
+    assert_eq!(DataType::Bool.size_in_bytes(), Some(1));
+    assert_eq!(DataType::Char.size_in_bytes(), Some(4));
+    assert_eq!(DataType::Int16.size_in_bytes(), Some(2));
+    assert_eq!(DataType::UInt32.size_in_bytes(), Some(4));
+    assert_eq!(DataType::Float64.size_in_bytes(), Some(8));
+    assert_eq!(DataType::Int128.size_in_bytes(), Some(16));
+
+    assert_eq!(DataType::String.size_in_bytes(), None);
+    assert_eq!(DataType::BigDecimal.size_in_bytes(), None);
+    for data_type in DataType::TEMPORAL {
+        assert_eq!(data_type.size_in_bytes(), None);
+    }
+}
+
+/// Test require_byte_len_for with fixed-width types
+#[test]
+fn test_require_byte_len_for_fixed_types() {
+    assert!(require_byte_len_for("value", &[0, 0, 0, 1], DataType::Int32).is_ok());
+    assert!(require_byte_len_for("flag", &[1], DataType::Bool).is_ok());
+
+    let err = require_byte_len_for("value", &[0, 0, 1], DataType::Int32).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'value' has type 'int32' which expects 4 bytes but got 3"
+    );
+    assert_eq!(err.parameter(), Some("value"));
+}
+
+/// Test require_byte_len_for with variable-width types
+#[test]
+fn test_require_byte_len_for_variable_types() {
+    let err = require_byte_len_for("name", b"abc", DataType::String).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'name' has type 'string' with no fixed size in bytes"
+    );
+    assert_eq!(err.parameter(), Some("name"));
+    assert!(require_byte_len_for("amount", &[], DataType::BigInteger).is_err());
+}
+
+/// Test that DataType::rank matches the order of DataType::ALL
+#[test]
+fn test_data_type_rank_matches_all() {
+    for (index, data_type) in DataType::ALL.iter().enumerate() {
+        assert_eq!(usize::from(data_type.rank()), index);
+    }
+}
+
+/// Test that a shuffled list of data types sorts into the documented order
+#[test]
+fn test_data_type_sort_order() {
+    let mut types = vec![
+        DataType::BigDecimal,
+        DataType::String,
+        DataType::Int64,
+        DataType::Date,
+        DataType::Bool,
+        DataType::Float32,
+        DataType::UInt8,
+        DataType::Char,
+        DataType::BigInteger,
+        DataType::Int8,
+    ];
+    types.sort();
+    assert_eq!(
+        types,
+        vec![
+            DataType::Bool,
+            DataType::Char,
+            DataType::Int8,
+            DataType::Int64,
+            DataType::UInt8,
+            DataType::Float32,
+            DataType::String,
+            DataType::Date,
+            DataType::BigInteger,
+            DataType::BigDecimal,
+        ]
+    );
+    assert!(DataType::Int128 < DataType::UInt8);
+    assert_eq!(DataType::ALL.iter().max(), Some(&DataType::BigDecimal));
+}
+
+/// A user-defined ID wrapping an `i32`
+struct UserId(i32);
+
+/// A user-defined email address wrapping a `String`
+struct Email(String);
+
+impl_data_type_of!(UserId => Int32, Email => String);
+
+/// Test that impl_data_type_of! maps user types to the given data types
+#[test]
+fn test_impl_data_type_of_macro() {
+    assert_eq!(UserId::DATA_TYPE, DataType::Int32);
+    assert_eq!(Email::DATA_TYPE, DataType::String);
+
+    fn data_type_of<T: DataTypeOf>(_value: &T) -> DataType {
+        T::DATA_TYPE
+    }
+    let id = UserId(42);
+    let email = Email("alice@example.com".to_string());
+    assert_eq!(data_type_of(&id), data_type_of(&id.0));
+    assert_eq!(data_type_of(&email), data_type_of(&email.0));
+}
@@ -12,13 +12,27 @@
 
 // Argument validation module tests
 mod argument {
+    pub(crate) mod bounded_tests;
     pub(crate) mod collection_tests;
     pub(crate) mod condition_tests;
     pub(crate) mod error_tests;
+    pub(crate) mod float_tests;
+    pub(crate) mod integer_tests;
     pub(crate) mod numeric_tests;
     pub(crate) mod option_tests;
+    pub(crate) mod path_tests;
+    pub(crate) mod string_collection_tests;
     pub(crate) mod string_tests;
+    pub(crate) mod temporal_tests;
+    #[cfg(feature = "typed-error")]
+    pub(crate) mod typed_error_tests;
 }
 
+// Boxed error tests
+mod box_error_tests;
+
 // Data type tests
 mod data_type_tests;
+
+// Typed value tests
+mod typed_value_tests;
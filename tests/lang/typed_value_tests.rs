@@ -0,0 +1,104 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # TypedValue Unit Tests
+//!
+//! Tests for the runtime typed value enum.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use bigdecimal::BigDecimal;
+use chrono::{
+    NaiveDate,
+    TimeZone,
+    Utc,
+};
+use num_bigint::BigInt;
+use prism3_core::{
+    DataType,
+    DataTypeOf,
+    TypedValue,
+};
+use std::str::FromStr;
+
+/// Test that constructed values report the data type of their Rust type
+#[test]
+fn test_typed_value_data_type() {
+    assert_eq!(TypedValue::from(true).data_type(), DataType::Bool);
+    assert_eq!(TypedValue::from('x').data_type(), DataType::Char);
+    assert_eq!(TypedValue::from(1i8).data_type(), DataType::Int8);
+    assert_eq!(TypedValue::from(1i32).data_type(), DataType::Int32);
+    assert_eq!(TypedValue::from(1u64).data_type(), DataType::UInt64);
+    assert_eq!(TypedValue::from(1u128).data_type(), DataType::UInt128);
+    assert_eq!(TypedValue::from(1.5f32).data_type(), DataType::Float32);
+    assert_eq!(TypedValue::from(1.5f64).data_type(), DataType::Float64);
+    assert_eq!(TypedValue::from("text").data_type(), DataType::String);
+    assert_eq!(
+        TypedValue::from("text".to_string()).data_type(),
+        DataType::String
+    );
+
+    let date = NaiveDate::from_ymd_opt(2025, 1, 31).unwrap();
+    assert_eq!(TypedValue::from(date).data_type(), DataType::Date);
+    assert_eq!(
+        TypedValue::from(date.and_hms_opt(12, 0, 0).unwrap()).data_type(),
+        DataType::DateTime
+    );
+    assert_eq!(
+        TypedValue::from(Utc.timestamp_opt(0, 0).unwrap()).data_type(),
+        DataType::Instant
+    );
+    assert_eq!(
+        TypedValue::from(BigInt::from(7)).data_type(),
+        DataType::BigInteger
+    );
+    assert_eq!(
+        TypedValue::from(BigDecimal::from_str("1.5").unwrap()).data_type(),
+        DataType::BigDecimal
+    );
+}
+
+/// Test that the runtime data type matches the compile-time DataTypeOf mapping
+#[test]
+fn test_typed_value_matches_data_type_of() {
+    assert_eq!(TypedValue::from(0i16).data_type(), i16::DATA_TYPE);
+    assert_eq!(TypedValue::from(0u8).data_type(), u8::DATA_TYPE);
+    assert_eq!(
+        TypedValue::from(String::new()).data_type(),
+        String::DATA_TYPE
+    );
+}
+
+/// Test extracting values with TryInto
+#[test]
+fn test_typed_value_try_into() {
+    let number: i32 = TypedValue::from(42i32).try_into().unwrap();
+    assert_eq!(number, 42);
+
+    let text: String = TypedValue::from("hello").try_into().unwrap();
+    assert_eq!(text, "hello");
+
+    let result: Result<i64, _> = TypedValue::from(42i32).try_into();
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Cannot convert a value of type 'int32' to type 'int64'"
+    );
+
+    let result: Result<bool, _> = TypedValue::from("true").try_into();
+    assert!(result.is_err());
+}
+
+/// Test equality and cloning of typed values
+#[test]
+fn test_typed_value_equality() {
+    let value = TypedValue::from(42i32);
+    assert_eq!(value.clone(), TypedValue::Int32(42));
+    assert_ne!(value, TypedValue::Int64(42));
+}
@@ -0,0 +1,83 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # JSON Tests
+//!
+//! Unit tests for the JSON value validation functions.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use prism3_core::util::{
+    require_json_array,
+    require_json_number,
+    require_json_object,
+    require_json_string,
+};
+use serde_json::json;
+
+#[test]
+fn test_require_json_string() {
+    assert_eq!(
+        require_json_string("host", &json!("localhost")).unwrap(),
+        "localhost"
+    );
+    let err = require_json_string("host", &json!(8080)).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'host' must be a JSON string but was a JSON number"
+    );
+}
+
+#[test]
+fn test_require_json_number() {
+    let port = json!(8080);
+    assert_eq!(
+        require_json_number("port", &port).unwrap().as_u64(),
+        Some(8080)
+    );
+    assert_eq!(
+        require_json_number("ratio", &json!(0.5)).unwrap().as_f64(),
+        Some(0.5)
+    );
+    let err = require_json_number("port", &json!("8080")).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'port' must be a JSON number but was a JSON string"
+    );
+}
+
+#[test]
+fn test_require_json_array() {
+    let hosts = json!(["a", "b"]);
+    assert_eq!(require_json_array("hosts", &hosts).unwrap().len(), 2);
+    let err = require_json_array("hosts", &json!({})).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'hosts' must be a JSON array but was a JSON object"
+    );
+}
+
+#[test]
+fn test_require_json_object() {
+    let config = json!({ "port": 8080, "debug": true });
+    let object = require_json_object("config", &config).unwrap();
+    assert_eq!(object.len(), 2);
+
+    let err = require_json_object("config", &json!(null)).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'config' must be a JSON object but was a JSON null"
+    );
+    let err = require_json_object("config", &config["debug"]).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'config' must be a JSON object but was a JSON boolean"
+    );
+}
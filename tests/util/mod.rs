@@ -14,4 +14,7 @@
 //!
 //! Haixing Hu
 
+pub mod json_tests;
+pub mod patterns_tests;
+pub mod serde_as_tests;
 pub mod tuple;
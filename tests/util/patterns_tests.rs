@@ -0,0 +1,94 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Patterns Tests
+//!
+//! Unit tests for the common regular expressions.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use prism3_core::{
+    patterns,
+    StringArgument,
+};
+
+#[test]
+fn test_accessors_return_shared_regex() {
+    assert!(std::ptr::eq(patterns::email(), patterns::email()));
+    assert!(std::ptr::eq(patterns::semver(), patterns::semver()));
+    assert_eq!(patterns::uuid().as_str(), patterns::UUID);
+}
+
+#[test]
+fn test_ipv4() {
+    for valid in ["0.0.0.0", "127.0.0.1", "192.168.10.254", "255.255.255.255"] {
+        assert!(patterns::ipv4().is_match(valid), "{}", valid);
+    }
+    for invalid in ["256.0.0.1", "1.2.3", "1.2.3.4.5", "01.2.3.4", "1.2.3.a", ""] {
+        assert!(!patterns::ipv4().is_match(invalid), "{}", invalid);
+    }
+}
+
+#[test]
+fn test_slug() {
+    for valid in ["post", "my-first-post", "v2-release-notes"] {
+        assert!(patterns::slug().is_match(valid), "{}", valid);
+    }
+    for invalid in ["", "-post", "post-", "my--post", "My-Post", "my_post"] {
+        assert!(!patterns::slug().is_match(invalid), "{}", invalid);
+    }
+}
+
+#[test]
+fn test_hex_color() {
+    for valid in ["#fff", "#FFFA", "#1e90ff", "#1E90FF80"] {
+        assert!(patterns::hex_color().is_match(valid), "{}", valid);
+    }
+    for invalid in ["fff", "#ff", "#fffff", "#1e90fg", "#1E90FF8"] {
+        assert!(!patterns::hex_color().is_match(invalid), "{}", invalid);
+    }
+}
+
+#[test]
+fn test_uuid() {
+    assert!(patterns::uuid().is_match("550e8400-e29b-41d4-a716-446655440000"));
+    assert!(patterns::uuid().is_match("550E8400-E29B-41D4-A716-446655440000"));
+    assert!(!patterns::uuid().is_match("550e8400e29b41d4a716446655440000"));
+    assert!(!patterns::uuid().is_match("550e8400-e29b-41d4-a716-44665544000g"));
+}
+
+#[test]
+fn test_semver() {
+    for valid in [
+        "0.1.0",
+        "1.2.3",
+        "10.20.30",
+        "1.0.0-alpha.1",
+        "1.0.0+build.5",
+        "1.0.0-rc.1+sha.abc",
+    ] {
+        assert!(patterns::semver().is_match(valid), "{}", valid);
+    }
+    for invalid in ["1.2", "01.2.3", "1.2.3-", "1.2.3-01", "v1.2.3", "1.2.3+"] {
+        assert!(!patterns::semver().is_match(invalid), "{}", invalid);
+    }
+}
+
+#[test]
+fn test_require_match_with_pattern() {
+    assert!("my-first-post"
+        .require_match("slug", patterns::slug())
+        .is_ok());
+    assert!("10.0.0.1"
+        .to_string()
+        .require_match("host", patterns::ipv4())
+        .is_ok());
+    assert!("My Post".require_match("slug", patterns::slug()).is_err());
+}
@@ -0,0 +1,83 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Serde Representation Tests
+//!
+//! Unit tests for the object and array serde representations of tuples.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use prism3_core::{
+    Pair,
+    Triple,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AsArray {
+    #[serde(with = "prism3_core::serde_as::pair_as_array")]
+    range: Pair<i32, String>,
+    #[serde(with = "prism3_core::serde_as::triple_as_array")]
+    color: Triple<u8, u8, u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AsObject {
+    range: Pair<i32, String>,
+    color: Triple<u8, u8, u8>,
+}
+
+#[test]
+fn test_array_representation_round_trip() {
+    let value = AsArray {
+        range: Pair::new(1, "end".to_string()),
+        color: Triple::new(255, 128, 0),
+    };
+    let encoded = serde_json::to_value(&value).unwrap();
+    assert_eq!(
+        encoded,
+        json!({ "range": [1, "end"], "color": [255, 128, 0] })
+    );
+    let decoded: AsArray = serde_json::from_value(encoded).unwrap();
+    assert_eq!(decoded, value);
+}
+
+#[test]
+fn test_object_representation_round_trip() {
+    let value = AsObject {
+        range: Pair::new(1, "end".to_string()),
+        color: Triple::new(255, 128, 0),
+    };
+    let encoded = serde_json::to_value(&value).unwrap();
+    assert_eq!(
+        encoded,
+        json!({
+            "range": { "first": 1, "second": "end" },
+            "color": { "first": 255, "second": 128, "third": 0 },
+        })
+    );
+    let decoded: AsObject = serde_json::from_value(encoded).unwrap();
+    assert_eq!(decoded, value);
+}
+
+#[test]
+fn test_array_representation_rejects_wrong_shape() {
+    let too_short = json!({ "range": [1], "color": [255, 128, 0] });
+    assert!(serde_json::from_value::<AsArray>(too_short).is_err());
+    let object = json!({
+        "range": { "first": 1, "second": "end" },
+        "color": [255, 128, 0],
+    });
+    assert!(serde_json::from_value::<AsArray>(object).is_err());
+}
@@ -14,5 +14,6 @@
 //!
 //! Haixing Hu
 
+pub mod nested_depth_tests;
 pub mod pair_tests;
 pub mod triple_tests;
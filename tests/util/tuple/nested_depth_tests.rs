@@ -0,0 +1,60 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Nested Depth Tests
+//!
+//! Unit tests for the nesting depth of pairs and triples.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use prism3_core::util::{
+    require_max_depth,
+    NestedDepth,
+};
+use prism3_core::{
+    Pair,
+    Triple,
+};
+
+#[test]
+fn test_scalar_depth() {
+    assert_eq!(42.nested_depth(), 1);
+    assert_eq!("leaf".nested_depth(), 1);
+    assert_eq!(String::from("leaf").nested_depth(), 1);
+}
+
+#[test]
+fn test_two_levels() {
+    assert_eq!(Pair::new(1, 2).nested_depth(), 2);
+    assert_eq!(Triple::new(1, 'a', true).nested_depth(), 2);
+}
+
+#[test]
+fn test_three_levels() {
+    let tree = Pair::new(1, Triple::new(2, 3, 4));
+    assert_eq!(tree.nested_depth(), 3);
+
+    // The deepest branch determines the depth
+    let tree = Triple::new(Pair::new(1, 2), 3, Pair::new(Pair::new(4, 5), 6));
+    assert_eq!(tree.nested_depth(), 4);
+}
+
+#[test]
+fn test_require_max_depth() {
+    let tree = Pair::new(1, Pair::new(2, 3));
+    assert!(require_max_depth(&tree, "tree", 3).is_ok());
+    assert!(require_max_depth(&tree, "tree", 10).is_ok());
+
+    let err = require_max_depth(&tree, "tree", 2).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'tree' has nesting depth 3 which exceeds the maximum of 2"
+    );
+}
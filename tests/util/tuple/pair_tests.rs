@@ -14,7 +14,13 @@
 //!
 //! Haixing Hu
 
+use prism3_core::util::{
+    map_to_pairs,
+    pairs_to_map,
+    PairSliceArgument,
+};
 use prism3_core::Pair;
+use std::collections::HashMap;
 
 #[test]
 fn test_new() {
@@ -78,6 +84,21 @@ fn test_from_tuple() {
     assert_eq!(pair.second, "hello");
 }
 
+#[test]
+fn test_from_labeled_tuple() {
+    let pair: Pair<String, i32> = ("count", 5).into();
+    assert_eq!(pair.first, "count");
+    assert_eq!(pair.second, 5);
+
+    let label = String::from("ratio");
+    let pair = Pair::<String, f64>::from((label.as_str(), 0.5));
+    assert_eq!(pair, Pair::new(String::from("ratio"), 0.5));
+
+    // The generic tuple conversion still applies to owned strings
+    let pair: Pair<String, i32> = (String::from("owned"), 1).into();
+    assert_eq!(pair.first, "owned");
+}
+
 #[test]
 fn test_into_from_pair() {
     let pair = Pair::new(1, "hello");
@@ -91,6 +112,12 @@ fn test_display() {
     assert_eq!(format!("{}", pair), "(1, hello)");
 }
 
+#[test]
+fn test_display_forwards_flags() {
+    assert_eq!(format!("{:.2}", Pair::new(1.0, 2.0)), "(1.00, 2.00)");
+    assert_eq!(format!("{:>3}", Pair::new(1, 22)), "(  1,  22)");
+}
+
 #[test]
 fn test_default() {
     let pair: Pair<i32, i32> = Pair::default();
@@ -174,3 +201,287 @@ fn test_pair_hash() {
     assert!(set.contains(&Pair::new(1, 2)));
     assert!(set.contains(&Pair::new(3, 4)));
 }
+
+#[test]
+fn test_approx_eq_f64() {
+    let pair = Pair::new(0.1 + 0.2, 1.0);
+    assert!(pair.approx_eq(&Pair::new(0.3, 1.0), 1e-9));
+    assert!(!pair.approx_eq(&Pair::new(0.3, 1.0), 0.0));
+
+    // The epsilon boundary is inclusive
+    let base = Pair::new(1.0, 2.0);
+    assert!(base.approx_eq(&Pair::new(1.5, 2.0), 0.5));
+    assert!(base.approx_eq(&Pair::new(1.0, 1.5), 0.5));
+    assert!(!base.approx_eq(&Pair::new(1.5, 2.0), 0.25));
+    assert!(!base.approx_eq(&Pair::new(1.0, 2.75), 0.5));
+    assert!(!base.approx_eq(&Pair::new(f64::NAN, 2.0), 1.0));
+}
+
+#[test]
+fn test_approx_eq_f32() {
+    let base = Pair::new(1.0f32, 2.0f32);
+    assert!(base.approx_eq(&Pair::new(1.25, 2.0), 0.25));
+    assert!(!base.approx_eq(&Pair::new(1.25, 2.0), 0.125));
+    assert!(base.approx_eq(&Pair::new(1.0001, 1.9999), 1e-3));
+}
+
+#[test]
+fn test_pairs_to_map() {
+    let map = pairs_to_map(vec![Pair::new("a", 1), Pair::new("b", 2)]);
+    assert_eq!(map.len(), 2);
+    assert_eq!(map["a"], 1);
+    assert_eq!(map["b"], 2);
+
+    // Duplicate keys keep the last value
+    let map = pairs_to_map(vec![
+        Pair::new("a", 1),
+        Pair::new("a", 2),
+        Pair::new("a", 3),
+    ]);
+    assert_eq!(map.len(), 1);
+    assert_eq!(map["a"], 3);
+
+    let empty: HashMap<i32, i32> = pairs_to_map(Vec::new());
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_map_to_pairs_round_trip() {
+    let mut map = HashMap::new();
+    map.insert("one".to_string(), 1);
+    map.insert("two".to_string(), 2);
+    map.insert("three".to_string(), 3);
+
+    let mut pairs = map_to_pairs(map.clone());
+    pairs.sort_by_key(|pair| pair.second);
+    assert_eq!(
+        pairs,
+        vec![
+            Pair::new("one".to_string(), 1),
+            Pair::new("two".to_string(), 2),
+            Pair::new("three".to_string(), 3),
+        ]
+    );
+
+    assert_eq!(pairs_to_map(pairs), map);
+}
+
+#[test]
+fn test_require_finite() {
+    let point = Pair::new(1.0, -2.5);
+    assert_eq!(point.require_finite("point").unwrap(), &point);
+
+    let err = Pair::new(f64::NAN, 1.0)
+        .require_finite("point")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'point' contains a non-finite component: (NaN, 1.0)"
+    );
+    assert_eq!(err.parameter(), Some("point"));
+    assert!(Pair::new(1.0, f64::INFINITY)
+        .require_finite("point")
+        .is_err());
+    assert!(Pair::new(f64::NEG_INFINITY, 0.0)
+        .require_finite("point")
+        .is_err());
+}
+
+#[test]
+fn test_eq_tuple() {
+    assert!(Pair::new(1, 2) == (1, 2));
+    assert!((1, 2) == Pair::new(1, 2));
+    assert!(Pair::new(1, 2) != (2, 1));
+    assert!((2, 1) != Pair::new(1, 2));
+    assert_eq!(Pair::new("a", 1.5), ("a", 1.5));
+    assert_eq!(("a", 1.5), Pair::new("a", 1.5));
+}
+
+#[test]
+fn test_require_all_positive() {
+    let size = Pair::new(640u32, 480u32);
+    assert_eq!(size.require_all_positive("size").unwrap(), &size);
+
+    let err = Pair::new(640u32, 0u32)
+        .require_all_positive("size")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'size' field 'second' must be positive but was: 0"
+    );
+
+    // The first failing element is reported
+    let err = Pair::new(-1, -2).require_all_positive("size").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'size' field 'first' must be positive but was: -1"
+    );
+    assert_eq!(err.parameter(), Some("size"));
+    assert!(Pair::new(0.5, 1.5).require_all_positive("size").is_ok());
+    assert!(Pair::new(f64::NAN, 1.5)
+        .require_all_positive("size")
+        .is_err());
+}
+
+#[test]
+fn test_require_all_non_negative() {
+    assert!(Pair::new(0, 0).require_all_non_negative("offset").is_ok());
+    assert!(Pair::new(3, 0).require_all_non_negative("offset").is_ok());
+
+    let err = Pair::new(0, -5)
+        .require_all_non_negative("offset")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'offset' field 'second' must be non-negative but was: -5"
+    );
+    assert_eq!(err.parameter(), Some("offset"));
+    assert!(Pair::new(-0.5, 1.0)
+        .require_all_non_negative("offset")
+        .is_err());
+    assert!(Pair::new(0.0, f64::NAN)
+        .require_all_non_negative("offset")
+        .is_err());
+}
+
+#[test]
+fn test_to_array_and_fold() {
+    assert_eq!(Pair::new(1, 2).to_array(), [1, 2]);
+    assert_eq!(Pair::new(3, 4).fold(0, |sum, x| sum + x), 7);
+    assert!(Pair::new(true, false).fold(false, |any, flag| any || flag));
+
+    // Elements are visited in order
+    let joined = Pair::new("a", "b").fold(String::new(), |mut acc, s| {
+        acc.push_str(s);
+        acc
+    });
+    assert_eq!(joined, "ab");
+}
+
+#[test]
+fn test_into_entry_and_insert_into() {
+    assert_eq!(Pair::new("a", 1).into_entry(), ("a", 1));
+
+    let mut map = HashMap::new();
+    for pair in [Pair::new("a", 1), Pair::new("b", 2), Pair::new("c", 3)] {
+        assert_eq!(pair.insert_into(&mut map), None);
+    }
+    assert_eq!(map.len(), 3);
+    assert_eq!(map["b"], 2);
+
+    // Inserting an existing key replaces and returns the old value
+    assert_eq!(Pair::new("b", 20).insert_into(&mut map), Some(2));
+    assert_eq!(map["b"], 20);
+    assert_eq!(map.len(), 3);
+}
+
+#[test]
+fn test_require_valid_ratio() {
+    assert_eq!(
+        Pair::new(1.0, 4.0).require_valid_ratio("weight").unwrap(),
+        0.25
+    );
+    assert_eq!(
+        Pair::new(-3.0, 2.0).require_valid_ratio("weight").unwrap(),
+        -1.5
+    );
+}
+
+#[test]
+fn test_require_valid_ratio_rejects_invalid_denominator() {
+    let err = Pair::new(1.0, 0.0)
+        .require_valid_ratio("weight")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'weight' denominator must be finite and non-zero but was: 0.0"
+    );
+    assert!(Pair::new(1.0, -0.0).require_valid_ratio("weight").is_err());
+    assert!(Pair::new(1.0, f64::NAN)
+        .require_valid_ratio("weight")
+        .is_err());
+    assert!(Pair::new(1.0, f64::INFINITY)
+        .require_valid_ratio("weight")
+        .is_err());
+
+    let err = Pair::new(f64::NAN, 2.0)
+        .require_valid_ratio("weight")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'weight' numerator must be finite but was: NaN"
+    );
+    assert_eq!(err.parameter(), Some("weight"));
+
+    let err = Pair::new(1e308, 1e-10)
+        .require_valid_ratio("weight")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'weight' ratio 1e308 / 1e-10 overflows to inf"
+    );
+}
+
+#[test]
+fn test_as_ref_tuple() {
+    let pair = Pair::new(String::from("key"), vec![1, 2]);
+    let (first, second) = pair.as_ref_tuple();
+    assert_eq!(first, "key");
+    assert_eq!(second.len(), 2);
+    // The pair is still usable
+    assert_eq!(pair.first, "key");
+}
+
+#[test]
+fn test_as_tuple_copy() {
+    let pair = Pair::new(1, 2.5);
+    let tuple: (i32, f64) = pair.as_tuple_copy();
+    assert_eq!(tuple, (1, 2.5));
+    assert_eq!(pair, tuple);
+}
+
+#[test]
+fn test_require_non_overlapping_disjoint() {
+    let slots = Vec::from([Pair::new(13, 15), Pair::new(9, 10), Pair::new(10, 12)]);
+    assert!(slots.require_non_overlapping("slots").is_ok());
+    assert!(slots[..0].require_non_overlapping("slots").is_ok());
+    assert!([Pair::new(5, 5), Pair::new(5, 5)]
+        .require_non_overlapping("slots")
+        .is_ok());
+}
+
+#[test]
+fn test_require_non_overlapping_independent_of_order() {
+    // A zero-length interval sharing its start with a longer one is adjacent to it
+    assert!([Pair::new(5, 5), Pair::new(5, 9)]
+        .require_non_overlapping("slots")
+        .is_ok());
+    assert!([Pair::new(5, 9), Pair::new(5, 5)]
+        .require_non_overlapping("slots")
+        .is_ok());
+}
+
+#[test]
+fn test_require_non_overlapping_overlapping() {
+    let slots = [Pair::new(13, 15), Pair::new(9, 11), Pair::new(10, 12)];
+    let err = slots.require_non_overlapping("slots").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'slots': intervals at indices 1 and 2 overlap: (9, 11) and (10, 12)"
+    );
+
+    // A long interval overlapping a later, non-adjacent one
+    let slots = [Pair::new(0, 100), Pair::new(10, 20), Pair::new(50, 60)];
+    let err = slots.require_non_overlapping("slots").unwrap_err();
+    assert!(err.message().contains("indices 0 and 1"));
+}
+
+#[test]
+fn test_require_non_overlapping_malformed() {
+    let slots = [Pair::new(1, 2), Pair::new(8, 3)];
+    let err = slots.require_non_overlapping("slots").unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Collection 'slots': interval at index 1 has start 8 after end 3"
+    );
+}
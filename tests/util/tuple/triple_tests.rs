@@ -14,7 +14,10 @@
 //!
 //! Haixing Hu
 
-use prism3_core::Triple;
+use prism3_core::{
+    Pair,
+    Triple,
+};
 
 #[test]
 fn test_new() {
@@ -100,6 +103,15 @@ fn test_display() {
     assert_eq!(format!("{}", triple), "(1, hello, true)");
 }
 
+#[test]
+fn test_display_forwards_flags() {
+    assert_eq!(
+        format!("{:.1}", Triple::new(1.0, 2.25, 3.0)),
+        "(1.0, 2.2, 3.0)"
+    );
+    assert_eq!(format!("{:03}", Triple::new(1, 2, 3)), "(001, 002, 003)");
+}
+
 #[test]
 fn test_default() {
     let triple: Triple<i32, i32, i32> = Triple::default();
@@ -204,3 +216,185 @@ fn test_map_all_fields() {
     assert_eq!(result.second, 40);
     assert_eq!(result.third, 90);
 }
+
+#[test]
+fn test_approx_eq_f64() {
+    let triple = Triple::new(0.1 + 0.2, 1.0, 2.0);
+    assert!(triple.approx_eq(&Triple::new(0.3, 1.0, 2.0), 1e-9));
+    assert!(!triple.approx_eq(&Triple::new(0.3, 1.0, 2.0), 0.0));
+
+    // The epsilon boundary is inclusive
+    let base = Triple::new(1.0, 2.0, 3.0);
+    assert!(base.approx_eq(&Triple::new(1.5, 2.0, 3.0), 0.5));
+    assert!(base.approx_eq(&Triple::new(1.0, 2.0, 3.5), 0.5));
+    assert!(!base.approx_eq(&Triple::new(1.0, 2.0, 3.75), 0.5));
+    assert!(!base.approx_eq(&Triple::new(1.0, f64::NAN, 3.0), 1.0));
+}
+
+#[test]
+fn test_approx_eq_f32() {
+    let base = Triple::new(1.0f32, 2.0f32, 3.0f32);
+    assert!(base.approx_eq(&Triple::new(1.0, 2.25, 3.0), 0.25));
+    assert!(!base.approx_eq(&Triple::new(1.0, 2.25, 3.0), 0.125));
+}
+
+#[test]
+fn test_require_finite() {
+    let point = Triple::new(1.0, -2.5, 0.0);
+    assert_eq!(point.require_finite("point").unwrap(), &point);
+
+    let err = Triple::new(1.0, 2.0, f64::NAN)
+        .require_finite("point")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'point' contains a non-finite component: (1.0, 2.0, NaN)"
+    );
+    assert_eq!(err.parameter(), Some("point"));
+    assert!(Triple::new(f64::INFINITY, 1.0, 2.0)
+        .require_finite("point")
+        .is_err());
+    assert!(Triple::new(0.0, f64::NEG_INFINITY, 2.0)
+        .require_finite("point")
+        .is_err());
+}
+
+#[test]
+fn test_pair_projections() {
+    let triple = Triple::new(1, String::from("hello"), true);
+    assert_eq!(
+        triple.clone().first_second(),
+        Pair::new(1, String::from("hello"))
+    );
+    assert_eq!(triple.clone().first_third(), Pair::new(1, true));
+    assert_eq!(
+        triple.second_third(),
+        Pair::new(String::from("hello"), true)
+    );
+
+    // Projections of a homogeneous triple keep the elements in order
+    let triple = Triple::new(1, 2, 3);
+    assert_eq!(triple.first_second(), Pair::new(1, 2));
+    assert_eq!(triple.first_third(), Pair::new(1, 3));
+    assert_eq!(triple.second_third(), Pair::new(2, 3));
+}
+
+#[test]
+fn test_eq_tuple() {
+    assert!(Triple::new(1, 2, 3) == (1, 2, 3));
+    assert!((1, 2, 3) == Triple::new(1, 2, 3));
+    assert!(Triple::new(1, 2, 3) != (1, 2, 4));
+    assert!((3, 2, 1) != Triple::new(1, 2, 3));
+    assert_eq!(Triple::new("a", 1, true), ("a", 1, true));
+    assert_eq!(("a", 1, true), Triple::new("a", 1, true));
+}
+
+#[test]
+fn test_to_array_and_fold() {
+    let triple: Triple<i32, i32, i32> = Triple::new(1, -2, 3);
+    assert_eq!(triple.to_array(), [1, -2, 3]);
+    assert_eq!(triple.fold(0, |sum, x| sum + x), 2);
+    assert_eq!(triple.fold(1, |product, x| product * x), -6);
+
+    let order = Triple::new(1, 2, 3).fold(Vec::new(), |mut acc, x| {
+        acc.push(x);
+        acc
+    });
+    assert_eq!(order, vec![1, 2, 3]);
+    assert!(!Triple::new(true, false, true).fold(true, |all, flag| all && flag));
+}
+
+#[test]
+fn test_require_value_within_in_range() {
+    let clamped = Triple::new(0, 5, 10);
+    assert_eq!(clamped.require_value_within("volume").unwrap(), &clamped);
+    assert!(Triple::new(0, 0, 10).require_value_within("volume").is_ok());
+    assert!(Triple::new(0, 10, 10)
+        .require_value_within("volume")
+        .is_ok());
+    assert!(Triple::new(0.0, 0.5, 1.0)
+        .require_value_within("ratio")
+        .is_ok());
+}
+
+#[test]
+fn test_require_value_within_below_lower() {
+    let err = Triple::new(0, -1, 10)
+        .require_value_within("volume")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'volume' value -1 must be greater than or equal to lower bound 0"
+    );
+    assert_eq!(err.parameter(), Some("volume"));
+}
+
+#[test]
+fn test_require_value_within_above_upper() {
+    let err = Triple::new(0, 15, 10)
+        .require_value_within("volume")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'volume' value 15 must be less than or equal to upper bound 10"
+    );
+    assert!(Triple::new(0.0, f64::NAN, 1.0)
+        .require_value_within("ratio")
+        .is_err());
+}
+
+#[test]
+fn test_as_ref_tuple() {
+    let triple = Triple::new(String::from("key"), vec![1, 2], true);
+    let (first, second, third) = triple.as_ref_tuple();
+    assert_eq!(first, "key");
+    assert_eq!(second.len(), 2);
+    assert!(*third);
+    assert_eq!(triple.first, "key");
+}
+
+#[test]
+fn test_as_tuple_copy() {
+    let triple = Triple::new(1, 2.5, 'c');
+    let tuple: (i32, f64, char) = triple.as_tuple_copy();
+    assert_eq!(tuple, (1, 2.5, 'c'));
+    assert_eq!(triple, tuple);
+}
+
+#[test]
+fn test_require_discriminant_non_negative_real_roots() {
+    let distinct = Triple::new(1.0, -3.0, 2.0);
+    assert_eq!(
+        distinct
+            .require_discriminant_non_negative("coeffs")
+            .unwrap(),
+        &distinct
+    );
+    // x² - 2x + 1 has a double root, so the discriminant is exactly zero
+    assert!(Triple::new(1.0, -2.0, 1.0)
+        .require_discriminant_non_negative("coeffs")
+        .is_ok());
+    assert!(Triple::new(0.0, 2.0, 5.0)
+        .require_discriminant_non_negative("coeffs")
+        .is_ok());
+}
+
+#[test]
+fn test_require_discriminant_non_negative_complex_roots() {
+    let err = Triple::new(1.0, 0.0, 1.0)
+        .require_discriminant_non_negative("coeffs")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Parameter 'coeffs' discriminant must be non-negative but was: -4"
+    );
+    assert_eq!(err.parameter(), Some("coeffs"));
+    assert!(Triple::new(f64::NAN, 0.0, 1.0)
+        .require_discriminant_non_negative("coeffs")
+        .is_err());
+
+    let err = Triple::new(f64::MAX, f64::MAX, f64::MAX)
+        .require_discriminant_non_negative("coeffs")
+        .unwrap_err();
+    assert!(err.message().contains("non-finite discriminant"));
+}